@@ -0,0 +1,75 @@
+//! Compare blocked CPU matrix multiplication against the original triple loop
+//!
+//! Run with `cargo bench --bench matmul` (add `--features parallel` for the multithreaded path)
+
+use std::hint::black_box;
+use std::time::{
+    Duration,
+    Instant
+};
+
+use oscirs_linalg::matrix::Matrix;
+
+/// Matrix sizes (n x n times n x n) to benchmark
+const SIZES: [usize; 4] = [64, 128, 256, 512];
+
+/// Minimum total time spent timing each implementation per size
+const MIN_DURATION: Duration = Duration::from_millis(500);
+
+// Original implementation indexing rhs column-wise (private function)
+fn naive_mul(lhs: &Matrix, rhs: &Matrix) -> Matrix {
+    let mut output_data: Vec<f32> = Vec::with_capacity(lhs.get_rows() * rhs.get_cols());
+
+    for lhs_row in 0..lhs.get_rows() {
+        for rhs_col in 0..rhs.get_cols() {
+            let mut dot_prod: f32 = 0.0;
+
+            for dot_index in 0..lhs.get_cols() {
+                dot_prod += lhs[[lhs_row, dot_index]] * rhs[[dot_index, rhs_col]];
+            }
+
+            output_data.push(dot_prod);
+        }
+    }
+
+    Matrix::new(output_data, lhs.get_rows(), rhs.get_cols()).unwrap()
+}
+
+// Return mean seconds per call of product, repeating until MIN_DURATION has elapsed (private function)
+fn time_per_call<F: FnMut() -> Matrix>(mut product: F) -> f64 {
+    let start: Instant = Instant::now();
+    let mut n_calls: u32 = 0;
+
+    while n_calls == 0 || start.elapsed() < MIN_DURATION {
+        black_box(product());
+        n_calls += 1;
+    }
+
+    start.elapsed().as_secs_f64() / n_calls as f64
+}
+
+fn main() {
+    println!("{:>6} {:>14} {:>15} {:>9}", "n", "naive GFLOP/s", "blocked GFLOP/s", "speedup");
+
+    for n in SIZES {
+        let lhs: Matrix = Matrix::from_fn(n, n, |row, col| ((row * 7 + col * 3) % 11) as f32 - 5.0);
+        let rhs: Matrix = Matrix::from_fn(n, n, |row, col| ((row * 5 + col * 2) % 13) as f32 - 6.0);
+
+        assert!(
+            naive_mul(&lhs, &rhs).approx_eq(&(&lhs * &rhs).unwrap(), 1e-5, 1e-3),
+            "Blocked product does not match naive product"
+        );
+
+        let naive_time: f64 = time_per_call(|| naive_mul(black_box(&lhs), black_box(&rhs)));
+        let blocked_time: f64 = time_per_call(|| (black_box(&lhs) * black_box(&rhs)).unwrap());
+
+        let flops: f64 = 2.0 * (n * n * n) as f64;
+        println!(
+            "{:>6} {:>14.2} {:>15.2} {:>8.1}x",
+            n,
+            flops / naive_time / 1e9,
+            flops / blocked_time / 1e9,
+            naive_time / blocked_time
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Pure Rust backend used when no OpenCL GPU is available
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+
+use super::{
+    occupied,
+    place,
+    Backend,
+    BoundArg,
+    BuiltinOp
+};
+
+/// Backend keeping matrices in host memory and computing on the CPU
+pub(crate) struct CpuBackend {
+    matrices: Vec<Option<Matrix>> // Stored and resulting matrices (None for free slots)
+}
+
+impl CpuBackend {
+    /// Create empty CPU backend
+    pub(crate) fn new() -> CpuBackend {
+        CpuBackend { matrices: Vec::with_capacity(crate::INIT_MEMORY_CAPACITY) }
+    }
+}
+
+impl Backend for CpuBackend {
+    fn store_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
+        if matrix.get_rows() * matrix.get_cols() != matrix.data.len() {
+            return Err(LAError::MatrixMismatchError)
+        }
+
+        place(&mut self.matrices, idx, matrix);
+
+        Ok(())
+    }
+
+    fn free_matrix(&mut self, idx: usize) -> Result<()> {
+        occupied(&self.matrices, idx)?;
+        self.matrices[idx] = None;
+
+        Ok(())
+    }
+
+    fn overwrite_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
+        if matrix.get_rows() * matrix.get_cols() != matrix.data.len() {
+            return Err(LAError::MatrixMismatchError)
+        }
+
+        occupied(&self.matrices, idx)?;
+        self.matrices[idx] = Some(matrix);
+
+        Ok(())
+    }
+
+    fn clear(&mut self) {
+        self.matrices.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    fn read_matrix(&mut self, idx: usize, rows: usize, cols: usize) -> Result<Matrix> {
+        let matrix: &Matrix = occupied(&self.matrices, idx)?;
+
+        if matrix.get_rows() != rows || matrix.get_cols() != cols {
+            return Err(LAError::MemoryError)
+        }
+
+        Ok(matrix.clone())
+    }
+
+    fn mat_mul(
+        &mut self,
+        left_idx: usize,
+        right_idx: usize,
+        output_idx: usize,
+        rows: usize,
+        interm: usize,
+        cols: usize
+    ) -> Result<()>
+    {
+        let left: &Matrix = occupied(&self.matrices, left_idx)?;
+        let right: &Matrix = occupied(&self.matrices, right_idx)?;
+
+        if left.get_rows() != rows || left.get_cols() != interm || right.get_cols() != cols {
+            return Err(LAError::SizeError)
+        }
+
+        let output: Matrix = (left * right)?;
+        place(&mut self.matrices, output_idx, output);
+
+        Ok(())
+    }
+
+    fn builtin(
+        &mut self,
+        op: BuiltinOp,
+        input_idcs: &[usize],
+        output_idx: usize,
+        rows: usize,
+        cols: usize
+    ) -> Result<()>
+    {
+        if input_idcs.len() != op.n_inputs() {
+            return Err(LAError::ArgumentError)
+        }
+
+        let input: &Matrix = occupied(&self.matrices, input_idcs[0])?;
+
+        if input.get_rows() != rows || input.get_cols() != cols {
+            return Err(LAError::SizeError)
+        }
+
+        let output: Matrix = match op {
+            BuiltinOp::Add => (input + occupied(&self.matrices, input_idcs[1])?)?,
+            BuiltinOp::Sub => (input - occupied(&self.matrices, input_idcs[1])?)?,
+            BuiltinOp::Hadamard => input.hadamard(occupied(&self.matrices, input_idcs[1])?)?,
+            BuiltinOp::AddScalar(scalar) => input + scalar,
+            BuiltinOp::MulScalar(scalar) => input * scalar,
+            BuiltinOp::Transpose => input.transpose(),
+            BuiltinOp::Sum(axis) => input.sum(axis),
+            BuiltinOp::Exp => input.map(f32::exp),
+            BuiltinOp::Ln => input.map(f32::ln),
+            BuiltinOp::Sqrt => input.map(f32::sqrt),
+            BuiltinOp::Tanh => input.map(f32::tanh)
+        };
+
+        place(&mut self.matrices, output_idx, output);
+
+        Ok(())
+    }
+
+    fn mat_mul_tile_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn new_kernel(&mut self, _program_source: &str, _kernel_name: &str, _options: &str) -> Result<usize> {
+        // OpenCL C source cannot be executed without an OpenCL device
+        Err(LAError::UnsupportedError)
+    }
+
+    fn kernel_arg_types(&self, _kernel_index: usize) -> Result<Vec<Option<String>>> {
+        Err(LAError::UnsupportedError)
+    }
+
+    fn execute(
+        &mut self,
+        _kernel_index: usize,
+        _args: Vec<BoundArg>,
+        _output_idx: usize,
+        _output_rows: usize,
+        _output_cols: usize,
+        _work_sizes: Vec<usize>
+    ) -> Result<()>
+    {
+        Err(LAError::UnsupportedError)
+    }
+}
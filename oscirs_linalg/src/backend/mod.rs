@@ -0,0 +1,142 @@
+//! Compute backends used by Calculator to store matrices and run kernels
+
+pub mod cpu;
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::elementwise::Axis;
+
+/// Built-in operation provided by every backend (OpenCL through the default kernels)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BuiltinOp {
+    Add, // Elementwise sum of two matrices
+    Sub, // Elementwise difference of two matrices
+    Hadamard, // Elementwise product of two matrices
+    AddScalar(f32), // Sum with scalar
+    MulScalar(f32), // Product with scalar
+    Transpose, // Transpose
+    Sum(Axis), // Sum along axis
+    Exp, // Elementwise exponential
+    Ln, // Elementwise natural logarithm
+    Sqrt, // Elementwise square root
+    Tanh // Elementwise hyperbolic tangent
+}
+
+impl BuiltinOp {
+    /// Return number of input matrices
+    pub(crate) fn n_inputs(self) -> usize {
+        match self {
+            BuiltinOp::Add | BuiltinOp::Sub | BuiltinOp::Hadamard => 2,
+            _ => 1
+        }
+    }
+
+    /// Return output shape for input matrices of shape rows x cols
+    pub(crate) fn output_shape(self, rows: usize, cols: usize) -> (usize, usize) {
+        match self {
+            BuiltinOp::Transpose => (cols, rows),
+            BuiltinOp::Sum(Axis::Row) => (rows, 1),
+            BuiltinOp::Sum(Axis::Col) => (1, cols),
+            _ => (rows, cols)
+        }
+    }
+}
+
+/// Kernel argument bound after the output buffer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BoundArg {
+    Float(f32), // Float scalar
+    Int(i32), // Int scalar
+    Matrix(usize) // Matrix in memory slot
+}
+
+/// Operations a Calculator backend must provide (memory slots are chosen by the Calculator)
+pub(crate) trait Backend {
+    /// Store matrix in memory slot idx
+    fn store_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()>;
+
+    /// Release matrix stored in memory slot idx
+    fn free_matrix(&mut self, idx: usize) -> Result<()>;
+
+    /// Replace matrix in memory slot idx, reusing its storage when the element count matches
+    fn overwrite_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()>;
+
+    /// Release every stored matrix
+    fn clear(&mut self);
+
+    /// Read rows x cols matrix in memory slot idx back to the host
+    fn read_matrix(&mut self, idx: usize, rows: usize, cols: usize) -> Result<Matrix>;
+
+    /// Multiply stored matrices (rows x interm and interm x cols) and store the result in slot output_idx
+    fn mat_mul(
+        &mut self,
+        left_idx: usize,
+        right_idx: usize,
+        output_idx: usize,
+        rows: usize,
+        interm: usize,
+        cols: usize
+    ) -> Result<()>;
+
+    /// Get tile size used by mat_mul (None when not tiled)
+    fn mat_mul_tile_size(&self) -> Option<usize>;
+
+    /// Apply built-in operation to stored rows x cols matrices and store the result in slot output_idx
+    fn builtin(
+        &mut self,
+        op: BuiltinOp,
+        input_idcs: &[usize],
+        output_idx: usize,
+        rows: usize,
+        cols: usize
+    ) -> Result<()>;
+
+    /// Compile custom kernel with compiler options and return its kernel index
+    fn new_kernel(&mut self, program_source: &str, kernel_name: &str, options: &str) -> Result<usize>;
+
+    /// Get OpenCL type name of each argument of compiled kernel (None where the device does not report it)
+    fn kernel_arg_types(&self, kernel_index: usize) -> Result<Vec<Option<String>>>;
+
+    /// Execute custom kernel with args bound in order and store the result in slot output_idx without reading it back
+    fn execute(
+        &mut self,
+        kernel_index: usize,
+        args: Vec<BoundArg>,
+        output_idx: usize,
+        output_rows: usize,
+        output_cols: usize,
+        work_sizes: Vec<usize>
+    ) -> Result<()>;
+}
+
+/// Bind floats, then ints, then matrices (argument order of default, fused and load_custom_fn kernels)
+pub(crate) fn bind_in_order(
+    input_floats: Option<Vec<f32>>,
+    input_ints: Option<Vec<i32>>,
+    input_mat_idcs: Vec<usize>
+) -> Vec<BoundArg>
+{
+    input_floats.unwrap_or_default().into_iter().map(BoundArg::Float)
+        .chain(input_ints.unwrap_or_default().into_iter().map(BoundArg::Int))
+        .chain(input_mat_idcs.into_iter().map(BoundArg::Matrix))
+        .collect()
+}
+
+/// Put value in memory slot idx, growing memory with empty slots if needed
+pub(crate) fn place<V>(slots: &mut Vec<Option<V>>, idx: usize, value: V) {
+    if idx >= slots.len() {
+        slots.resize_with(idx + 1, || None);
+    }
+
+    slots[idx] = Some(value);
+}
+
+/// Get value in occupied memory slot idx
+pub(crate) fn occupied<V>(slots: &[Option<V>], idx: usize) -> Result<&V> {
+    match slots.get(idx) {
+        Some(Some(value)) => Ok(value),
+        Some(None) => Err(LAError::MemoryError),
+        None => Err(LAError::IndexError)
+    }
+}
@@ -2,109 +2,707 @@
 //! 
 //! Stores input and resultant matrices in GPU memory buffers for repeated use
 //! 
-//! Includes support for compilation and execution of custom kernels
+//! Resident operations leave results on the device until they are fetched
+//! 
+//! Falls back to a pure Rust CPU backend when no OpenCL GPU is available
+//! 
+//! Includes support for compilation and execution of custom kernels (OpenCL backend only)
+
+use std::collections::HashMap;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering
+};
+
+use opencl3::device::Device;
 
 use crate::Result;
 use crate::err::LAError;
 use crate::matrix::Matrix;
+use crate::expr::{
+    Expr,
+    Fused,
+    Node,
+    FUSED_KERNEL_NAME
+};
+use crate::kernel::{
+    CustomKernel,
+    KernelArg,
+    KernelBuilder
+};
 use crate::memory::MemoryHandler;
+use crate::elementwise::Axis;
+use crate::backend::{
+    bind_in_order,
+    Backend,
+    BoundArg,
+    BuiltinOp
+};
+use crate::backend::cpu::CpuBackend;
 
 type ResultFunction = Box<dyn Fn(
     usize,
-    &mut MemoryHandler,
+    &mut dyn Backend,
     Option<Vec<f32>>,
     Option<Vec<i32>>,
     Vec<usize>,
     usize,
     usize,
+    usize,
     Vec<usize>
-) -> Result<(Matrix, usize)>>;
+) -> Result<()>>;
 
 /// Shortcut type definition for closure defining output parameters for custom kernel
 pub type ParameterFunction = Box<dyn Fn(Vec<&Matrix>) -> Result<(usize, usize, Vec<usize>)>>;
 
+/// Source of unique Calculator ids so handles cannot be used with another Calculator
+static NEXT_CALCULATOR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Opaque reference to a matrix stored in Calculator memory, carrying its shape
+///
+/// Handles become stale once their matrix is freed, overwritten or cleared, and are then rejected with StaleHandleError
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatrixHandle {
+    calculator_id: usize, // Id of Calculator that issued the handle
+    index: usize, // Memory slot in Calculator and backend memory
+    generation: u32, // Generation of memory slot when handle was issued
+    rows: usize, // Number of rows of referenced matrix
+    cols: usize // Number of columns of referenced matrix
+}
+
+impl MatrixHandle {
+    /// Get number of rows of referenced matrix
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get number of columns of referenced matrix
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+}
+
+/// Kind of compute backend performing Calculator operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    OpenCl, // OpenCL device
+    Cpu // Pure Rust fallback on the host
+}
+
+/// Type of an OpenCL device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceType {
+    Gpu, // Graphics processor
+    Cpu, // OpenCL implementation on the host processor (e.g. PoCL)
+    Accelerator, // Dedicated accelerator
+    Other // Any other device type
+}
+
+/// Description of an available OpenCL device
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    pub platform_index: usize, // Index of platform in the platform list
+    pub platform_name: String, // Name of platform
+    pub device_index: usize, // Index of device within its platform
+    pub name: String, // Name of device
+    pub vendor: String, // Device vendor
+    pub version: String, // OpenCL version supported by device
+    pub device_type: DeviceType, // Type of device
+    pub compute_units: u32, // Number of parallel compute units
+    pub global_mem_size: u64, // Global memory in bytes
+    pub local_mem_size: u64, // Local memory per work-group in bytes
+    pub max_work_group_size: usize // Maximum number of work-items in a work-group
+}
+
+/// Builder selecting the backend and OpenCL device used by a Calculator
+#[derive(Debug, Clone, Default)]
+pub struct CalculatorBuilder {
+    backend_kind: Option<BackendKind>, // Required backend
+    device_type: Option<DeviceType>, // Required device type
+    platform_index: Option<usize>, // Required platform index
+    device_index: Option<usize>, // Required device index within platform
+    device_name: Option<String>, // Case insensitive substring of required device name
+    max_tile_size: Option<usize>, // Largest tile size allowed for tiled mat_mul
+    cpu_fallback: bool // Whether to use the CPU backend when an explicit selection matches nothing
+}
+
+impl CalculatorBuilder {
+    /// Create builder with no selection (first GPU, else CPU backend)
+    pub fn new() -> CalculatorBuilder {
+        CalculatorBuilder::default()
+    }
+
+    /// Require backend kind (BackendKind::Cpu skips device discovery)
+    pub fn backend(mut self, backend_kind: BackendKind) -> CalculatorBuilder {
+        self.backend_kind = Some(backend_kind);
+        self
+    }
+
+    /// Require device type
+    pub fn device_type(mut self, device_type: DeviceType) -> CalculatorBuilder {
+        self.device_type = Some(device_type);
+        self
+    }
+
+    /// Require platform by index in the platform list
+    pub fn platform_index(mut self, platform_index: usize) -> CalculatorBuilder {
+        self.platform_index = Some(platform_index);
+        self
+    }
+
+    /// Require device by index within its platform
+    pub fn device_index(mut self, device_index: usize) -> CalculatorBuilder {
+        self.device_index = Some(device_index);
+        self
+    }
+
+    /// Require device whose name contains name (case insensitive)
+    pub fn device_name(mut self, name: &str) -> CalculatorBuilder {
+        self.device_name = Some(name.to_lowercase());
+        self
+    }
+
+    /// Limit tile size of tiled mat_mul (below the smallest candidate tile, mat_mul uses the untiled kernel)
+    pub fn max_mat_mul_tile_size(mut self, max_tile_size: usize) -> CalculatorBuilder {
+        self.max_tile_size = Some(max_tile_size);
+        self
+    }
+
+    /// Use CPU backend instead of returning DeviceNotFoundError when an explicit selection matches nothing
+    pub fn cpu_fallback(mut self, cpu_fallback: bool) -> CalculatorBuilder {
+        self.cpu_fallback = cpu_fallback;
+        self
+    }
+
+    /// Create Calculator on the first device matching the selection
+    pub fn build(self) -> Result<Calculator> {
+        if self.backend_kind == Some(BackendKind::Cpu) {
+            return Ok(Calculator::init_cpu())
+        }
+
+        let explicit: bool = self.backend_kind.is_some()
+            || self.device_type.is_some()
+            || self.platform_index.is_some()
+            || self.device_index.is_some()
+            || self.device_name.is_some();
+
+        // Without an explicit selection only GPUs are considered
+        let device_type: Option<DeviceType> = if explicit { self.device_type } else { Some(DeviceType::Gpu) };
+
+        // Broken OpenCL installations fall back to the CPU backend like an absent one, unless a device was requested
+        let all_devices: Vec<(DeviceInfo, Device)> = match MemoryHandler::all_devices() {
+            Ok(all_devices) => all_devices,
+            Err(_) if !explicit || self.cpu_fallback => return Ok(Calculator::init_cpu()),
+            Err(error) => return Err(error)
+        };
+
+        let selected = all_devices
+            .into_iter()
+            .find(|(info, _)| {
+                device_type.is_none_or(|required| info.device_type == required)
+                    && self.platform_index.is_none_or(|required| info.platform_index == required)
+                    && self.device_index.is_none_or(|required| info.device_index == required)
+                    && self.device_name.as_ref().is_none_or(|required| info.name.to_lowercase().contains(required))
+            });
+
+        match selected {
+            Some((info, device)) => {
+                // Initialize vector of kernel names
+                let program_vec: Vec<&str> = super::PROGRAM_LIST.to_vec();
+
+                // Create memory handler using program source and kernel names
+                let memory_handler: MemoryHandler = MemoryHandler::new(device, super::PROGRAM_SOURCE, program_vec, self.max_tile_size)?;
+
+                Ok(Calculator::with_backend(Box::new(memory_handler), BackendKind::OpenCl, Some(info)))
+            },
+            None if !explicit || self.cpu_fallback => Ok(Calculator::init_cpu()),
+            None => Err(LAError::DeviceNotFoundError)
+        }
+    }
+}
+
+/// Contents of a Calculator memory slot
+enum Slot {
+    Free, // No matrix stored
+    Resident, // Matrix only in backend memory (not yet fetched)
+    Cached(Matrix) // Matrix in backend memory with a host copy
+}
+
 /// Wrapper that manages storage of matrices and custom kernels and manages calculation operations
 pub struct Calculator {
-    memory_handler: MemoryHandler, // Memory handler
-    matrices: Vec<Matrix>, // Calculator memory vector
+    id: usize, // Unique id stamped on issued handles
+    backend: Box<dyn Backend>, // Compute backend
+    backend_kind: BackendKind, // Kind of compute backend
+    device_info: Option<DeviceInfo>, // Selected OpenCL device (None on CPU backend)
+    slots: Vec<Slot>, // Calculator memory vector
+    generations: Vec<u32>, // Current generation of each memory slot
+    free_slots: Vec<usize>, // Freed memory slots available for reuse
+    fused_kernels: HashMap<String, usize>, // Backend kernel index of each compiled fused kernel source
     customs: Vec<ResultFunction>,
     params_customs: Vec<ParameterFunction>,
     custom_idcs: Vec<usize>
 }
 
 impl Calculator {
-    /// Initializes Calculator struct
+    /// Initializes Calculator struct, using the first OpenCL GPU if one exists and the CPU otherwise
     pub fn init() -> Result<Calculator> {
-        // Initialize vector of kernel names
-        let program_vec: Vec<&str> = super::PROGRAM_LIST.to_vec();
-        
-        // Create memory handler using program source and kernel names
-        let memory_handler: MemoryHandler = MemoryHandler::new(super::PROGRAM_SOURCE, program_vec)?;
+        CalculatorBuilder::new().build()
+    }
+
+    /// Initializes Calculator struct on the CPU backend regardless of available devices
+    pub fn init_cpu() -> Calculator {
+        Calculator::with_backend(Box::new(CpuBackend::new()), BackendKind::Cpu, None)
+    }
+
+    /// Return builder for selecting backend and device
+    pub fn builder() -> CalculatorBuilder {
+        CalculatorBuilder::new()
+    }
+
+    /// List every available OpenCL device across all platforms (empty if no platform is installed)
+    pub fn list_devices() -> Result<Vec<DeviceInfo>> {
+        let devices: Vec<DeviceInfo> = MemoryHandler::all_devices()?
+            .into_iter()
+            .map(|(info, _)| info)
+            .collect();
+
+        Ok(devices)
+    }
+
+    /// Get kind of backend performing calculations
+    pub fn get_backend_kind(&self) -> BackendKind {
+        self.backend_kind
+    }
+
+    /// Get description of selected OpenCL device (None on CPU backend)
+    pub fn get_device_info(&self) -> Option<&DeviceInfo> {
+        self.device_info.as_ref()
+    }
+
+    /// Get tile size of tiled mat_mul kernel (None on CPU backend or when the untiled kernel is used)
+    pub fn get_mat_mul_tile_size(&self) -> Option<usize> {
+        self.backend.mat_mul_tile_size()
+    }
 
-        // Create empty memory vector
-        let mat_vector: Vec<Matrix> = Vec::with_capacity(super::INIT_MEMORY_CAPACITY);
+    // Create calculator with empty memory around backend (private function)
+    fn with_backend(backend: Box<dyn Backend>, backend_kind: BackendKind, device_info: Option<DeviceInfo>) -> Calculator {
+        // Create empty memory vectors
+        let slot_vector: Vec<Slot> = Vec::with_capacity(super::INIT_MEMORY_CAPACITY);
+        let generations_vector: Vec<u32> = Vec::with_capacity(super::INIT_MEMORY_CAPACITY);
 
         let customs_vector: Vec<ResultFunction> = Vec::default();
         let params_customs_vector: Vec<ParameterFunction> = Vec::default();
         let custom_idcs_vector: Vec<usize> = Vec::default();
 
         // Create and return new memory calculator
-        let output: Calculator = Calculator {
-            memory_handler: memory_handler,
-            matrices: mat_vector,
+        Calculator {
+            id: NEXT_CALCULATOR_ID.fetch_add(1, Ordering::Relaxed),
+            backend,
+            backend_kind,
+            device_info,
+            slots: slot_vector,
+            generations: generations_vector,
+            free_slots: Vec::default(),
+            fused_kernels: HashMap::default(),
             customs: customs_vector,
             params_customs: params_customs_vector,
             custom_idcs: custom_idcs_vector
+        }
+    }
+
+    // Get memory slot the next stored matrix will occupy (private function)
+    fn next_slot(&self) -> usize {
+        self.free_slots.last().copied().unwrap_or(self.slots.len())
+    }
+
+    // Fill the slot given by next_slot and issue a handle for a rows x cols matrix (private function)
+    fn fill_next_slot(&mut self, slot: Slot, rows: usize, cols: usize) -> MatrixHandle {
+        let index: usize = match self.free_slots.pop() {
+            Some(idx) => {
+                self.slots[idx] = slot;
+                idx
+            },
+            None => {
+                self.slots.push(slot);
+                self.generations.push(0);
+                self.slots.len() - 1
+            }
         };
-        Ok(output)
+
+        MatrixHandle { calculator_id: self.id, index, generation: self.generations[index], rows, cols }
+    }
+
+    // Reject stale or foreign handles (private function)
+    fn check(&self, handle: MatrixHandle) -> Result<()> {
+        let live: bool = handle.calculator_id == self.id
+            && self.generations.get(handle.index) == Some(&handle.generation)
+            && !matches!(self.slots[handle.index], Slot::Free);
+
+        if !live {
+            return Err(LAError::StaleHandleError)
+        }
+
+        Ok(())
+    }
+
+    // Read device-resident matrix back to the host and keep the copy in its slot (private function)
+    fn cache(&mut self, handle: MatrixHandle) -> Result<()> {
+        self.check(handle)?;
+
+        if let Slot::Resident = self.slots[handle.index] {
+            let matrix: Matrix = self.backend.read_matrix(handle.index, handle.rows, handle.cols)?;
+            self.slots[handle.index] = Slot::Cached(matrix);
+        }
+
+        Ok(())
+    }
+
+    // Get host copy of matrix referenced by handle (private function)
+    fn host_copy(&self, handle: MatrixHandle) -> Result<&Matrix> {
+        self.check(handle)?;
+
+        match &self.slots[handle.index] {
+            Slot::Cached(matrix) => Ok(matrix),
+            _ => Err(LAError::MemoryError)
+        }
+    }
+
+    // Empty memory slot and invalidate every handle to it (private function)
+    fn release_slot(&mut self, idx: usize) {
+        self.slots[idx] = Slot::Free;
+        self.generations[idx] = self.generations[idx].wrapping_add(1);
+        self.free_slots.push(idx);
     }
 
-    /// Store matrix to calculator and gpu memory
-    pub fn store_matrix(&mut self, matrix: Matrix) -> Result<usize> {
+    /// Store matrix to calculator and backend memory
+    pub fn store_matrix(&mut self, matrix: Matrix) -> Result<MatrixHandle> {
+        // Store matrix to backend memory
+        self.backend.store_matrix(self.next_slot(), matrix.clone())?;
+
         // Store matrix to calculator memory
-        self.matrices.push(matrix.clone());
+        let rows: usize = matrix.get_rows();
+        let cols: usize = matrix.get_cols();
+        Ok(self.fill_next_slot(Slot::Cached(matrix), rows, cols))
+    }
+
+    /// Read matrix referenced by handle back to the host (later fetches reuse the host copy)
+    pub fn fetch(&mut self, handle: MatrixHandle) -> Result<Matrix> {
+        self.cache(handle)?;
+
+        Ok(self.host_copy(handle)?.clone())
+    }
+
+    /// Free matrix from calculator and backend memory (the handle is rejected afterwards)
+    pub fn free(&mut self, handle: MatrixHandle) -> Result<()> {
+        self.check(handle)?;
+
+        self.backend.free_matrix(handle.index)?;
+        self.release_slot(handle.index);
+
+        Ok(())
+    }
+
+    /// Replace stored matrix, re-uploading it into the existing backend buffer, and return its new handle
+    pub fn overwrite(&mut self, handle: MatrixHandle, matrix: Matrix) -> Result<MatrixHandle> {
+        self.check(handle)?;
+
+        self.backend.overwrite_matrix(handle.index, matrix.clone())?;
+
+        // New generation invalidates handles carrying the previous shape
+        let generation: u32 = self.generations[handle.index].wrapping_add(1);
+        self.generations[handle.index] = generation;
 
-        // Store matrix to gpu memory
-        let output_idx: usize = self.memory_handler
-            .store_matrix(matrix)?;
+        let new_handle: MatrixHandle = MatrixHandle {
+            generation,
+            rows: matrix.get_rows(),
+            cols: matrix.get_cols(),
+            ..handle
+        };
+        self.slots[handle.index] = Slot::Cached(matrix);
+
+        Ok(new_handle)
+    }
+
+    /// Free every matrix from calculator and backend memory (all previous handles are rejected afterwards)
+    pub fn clear(&mut self) {
+        self.backend.clear();
 
-        // Ensure parity between calculator and gpu memory
-        if output_idx != (self.matrices.len() - 1) {
-            return Err(LAError::MemoryError)
+        self.free_slots.clear();
+        for idx in (0..self.slots.len()).rev() {
+            self.release_slot(idx);
         }
+    }
+
+    /// Multiply Matrix and Matrix, reading the result back to the host
+    pub fn mat_mul(&mut self, left: MatrixHandle, right: MatrixHandle) -> Result<(Matrix, MatrixHandle)> {
+        let output_handle: MatrixHandle = self.mat_mul_resident(left, right)?;
 
-        Ok(output_idx)
+        Ok((self.fetch(output_handle)?, output_handle))
     }
 
-    /// Multiply Matrix and Matrix
-    pub fn mat_mul(&mut self, left_idx: usize, right_idx: usize) -> Result<(Matrix, usize)> {
-        let left: &Matrix = &self.matrices[left_idx];
-        let right: &Matrix = &self.matrices[right_idx];
+    /// Multiply Matrix and Matrix, leaving the result in backend memory until fetched
+    pub fn mat_mul_resident(&mut self, left: MatrixHandle, right: MatrixHandle) -> Result<MatrixHandle> {
+        self.check(left)?;
+        self.check(right)?;
 
-        if left.get_cols() != right.get_rows() {
+        if left.cols != right.rows {
             return Err(LAError::SizeError)
         }
 
-        let rows: usize = left.get_rows();
-        let interm: usize = left.get_cols();
-        let cols: usize = right.get_cols();
-
-        let (output, output_idx) = self.memory_handler.execute_and_read(
-            0,
-            None,
-            Some(vec![cols as i32, interm as i32]),
-            vec![left_idx, right_idx],
-            rows,
-            cols,
-            vec![rows, cols]
+        self.backend.mat_mul(
+            left.index,
+            right.index,
+            self.next_slot(),
+            left.rows,
+            left.cols,
+            right.cols
         )?;
 
-        self.matrices.push(output.clone());
+        Ok(self.fill_next_slot(Slot::Resident, left.rows, right.cols))
+    }
+
+    // Apply built-in operation to stored matrices, leaving the result in backend memory (private function)
+    fn builtin(&mut self, op: BuiltinOp, inputs: &[MatrixHandle]) -> Result<MatrixHandle> {
+        for handle in inputs {
+            self.check(*handle)?;
+        }
+
+        // Every input of a built-in operation has the shape of the first
+        let rows: usize = inputs[0].rows;
+        let cols: usize = inputs[0].cols;
+
+        if inputs.iter().any(|handle| handle.rows != rows || handle.cols != cols) {
+            return Err(LAError::SizeError)
+        }
+
+        let input_idcs: Vec<usize> = inputs.iter().map(|handle| handle.index).collect();
+        self.backend.builtin(op, &input_idcs, self.next_slot(), rows, cols)?;
+
+        let (output_rows, output_cols) = op.output_shape(rows, cols);
+        Ok(self.fill_next_slot(Slot::Resident, output_rows, output_cols))
+    }
+
+    /// Add matrices elementwise on the backend (result stays in backend memory until fetched)
+    pub fn add(&mut self, left: MatrixHandle, right: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Add, &[left, right])
+    }
+
+    /// Subtract right from left elementwise on the backend
+    pub fn sub(&mut self, left: MatrixHandle, right: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Sub, &[left, right])
+    }
+
+    /// Multiply matrices elementwise (Hadamard product) on the backend
+    pub fn hadamard(&mut self, left: MatrixHandle, right: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Hadamard, &[left, right])
+    }
+
+    /// Add scalar to every element on the backend
+    pub fn add_scalar(&mut self, handle: MatrixHandle, scalar: f32) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::AddScalar(scalar), &[handle])
+    }
+
+    /// Subtract scalar from every element on the backend
+    pub fn sub_scalar(&mut self, handle: MatrixHandle, scalar: f32) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::AddScalar(-scalar), &[handle])
+    }
+
+    /// Multiply every element by scalar on the backend
+    pub fn mul_scalar(&mut self, handle: MatrixHandle, scalar: f32) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::MulScalar(scalar), &[handle])
+    }
+
+    /// Transpose matrix on the backend
+    pub fn transpose(&mut self, handle: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Transpose, &[handle])
+    }
+
+    /// Sum elements along axis on the backend (rows x 1 for Axis::Row, 1 x cols for Axis::Col)
+    pub fn sum(&mut self, handle: MatrixHandle, axis: Axis) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Sum(axis), &[handle])
+    }
+
+    /// Apply exponential to every element on the backend
+    pub fn exp(&mut self, handle: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Exp, &[handle])
+    }
+
+    /// Apply natural logarithm to every element on the backend
+    pub fn ln(&mut self, handle: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Ln, &[handle])
+    }
+
+    /// Apply square root to every element on the backend
+    pub fn sqrt(&mut self, handle: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Sqrt, &[handle])
+    }
+
+    /// Apply hyperbolic tangent to every element on the backend
+    pub fn tanh(&mut self, handle: MatrixHandle) -> Result<MatrixHandle> {
+        self.builtin(BuiltinOp::Tanh, &[handle])
+    }
+
+    /// Evaluate lazy expression and read only the final result back to the host
+    pub fn eval(&mut self, expr: &Expr) -> Result<Matrix> {
+        let output_handle: MatrixHandle = self.eval_resident(expr)?;
+        let output: Matrix = self.fetch(output_handle)?;
+
+        // Bare inputs evaluate to their own handle, which stays stored
+        if !matches!(expr.node, Node::Input(_)) {
+            self.free(output_handle)?;
+        }
+
+        Ok(output)
+    }
+
+    /// Evaluate lazy expression, leaving the result in backend memory (bare inputs return their own handle)
+    /// 
+    /// Matrix products run as individual kernels and every elementwise subtree runs as one fused kernel, with
+    /// intermediate results freed afterwards. The CPU backend evaluates with the Matrix operators instead
+    pub fn eval_resident(&mut self, expr: &Expr) -> Result<MatrixHandle> {
+        // Reject mismatched dimensions before any work is done
+        expr.shape()?;
+
+        if self.backend_kind == BackendKind::Cpu {
+            let output: Matrix = self.eval_host(expr)?;
+            return self.store_matrix(output)
+        }
+
+        let mut temporaries: Vec<MatrixHandle> = Vec::new();
+        let output_result: Result<MatrixHandle> = self.eval_device(expr, &mut temporaries);
+
+        // Free intermediates, keeping only the final result
+        for handle in temporaries {
+            if output_result.as_ref().ok() != Some(&handle) {
+                self.free(handle)?;
+            }
+        }
+
+        output_result
+    }
+
+    // Evaluate expression with backend kernels, recording every result created (private function)
+    fn eval_device(&mut self, expr: &Expr, temporaries: &mut Vec<MatrixHandle>) -> Result<MatrixHandle> {
+        let output_handle: MatrixHandle = match &expr.node {
+            Node::Input(handle) => {
+                self.check(*handle)?;
+                return Ok(*handle)
+            },
+            Node::MatMul(lhs, rhs) => {
+                let lhs_handle: MatrixHandle = self.eval_device(lhs, temporaries)?;
+                let rhs_handle: MatrixHandle = self.eval_device(rhs, temporaries)?;
+
+                self.mat_mul_resident(lhs_handle, rhs_handle)?
+            },
+            _ => {
+                let fused: Fused = Fused::lower(expr);
+
+                let leaf_idcs: Vec<usize> = fused.leaves
+                    .iter()
+                    .map(|leaf| self.eval_device(leaf, temporaries).map(|handle| handle.index))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let kernel_index: usize = self.fused_kernel(fused.source())?;
+                let (rows, cols) = expr.shape()?;
+
+                self.backend.execute(
+                    kernel_index,
+                    bind_in_order(Some(fused.scalars), None, leaf_idcs),
+                    self.next_slot(),
+                    rows,
+                    cols,
+                    vec![rows * cols]
+                )?;
+
+                self.fill_next_slot(Slot::Resident, rows, cols)
+            }
+        };
+
+        temporaries.push(output_handle);
+
+        Ok(output_handle)
+    }
+
+    // Evaluate expression on the host with Matrix operators (private function)
+    fn eval_host(&mut self, expr: &Expr) -> Result<Matrix> {
+        match &expr.node {
+            Node::Input(handle) => self.fetch(*handle),
+            Node::Add(lhs, rhs) => self.eval_host(lhs)? + self.eval_host(rhs)?,
+            Node::Sub(lhs, rhs) => self.eval_host(lhs)? - self.eval_host(rhs)?,
+            Node::Neg(operand) => Ok(-self.eval_host(operand)?),
+            Node::Scale(operand, scalar) => Ok(self.eval_host(operand)? * *scalar),
+            Node::MatMul(lhs, rhs) => self.eval_host(lhs)? * self.eval_host(rhs)?
+        }
+    }
+
+    // Get kernel index of fused kernel source, compiling it on first use (private function)
+    fn fused_kernel(&mut self, source: String) -> Result<usize> {
+        if let Some(kernel_index) = self.fused_kernels.get(&source) {
+            return Ok(*kernel_index)
+        }
+
+        let kernel_index: usize = self.backend.new_kernel(&source, FUSED_KERNEL_NAME, "")?;
+        self.fused_kernels.insert(source, kernel_index);
+
+        Ok(kernel_index)
+    }
+
+    /// Compile custom kernel declared by builder, checking the declared arguments against the compiled kernel
+    ///
+    /// Rules referring to undeclared input matrices return IndexError, and a declared signature that does not match the kernel returns ArgumentError
+    pub fn load_kernel(&mut self, builder: KernelBuilder) -> Result<CustomKernel> {
+        builder.validate()?;
+
+        // Argument info lets the device report argument type names
+        let options: String = format!("{} -cl-kernel-arg-info", builder.options);
+        let kernel_index: usize = self.backend.new_kernel(&builder.program_source, &builder.kernel_name, options.trim_start())?;
 
-        Ok((output, output_idx))
+        builder.check_kernel_args(&self.backend.kernel_arg_types(kernel_index)?)?;
+
+        Ok(CustomKernel { calculator_id: self.id, kernel_index, signature: builder })
+    }
+
+    /// Run custom kernel on args in declared order, reading the result back to the host
+    pub fn run_kernel(&mut self, kernel: &CustomKernel, args: &[KernelArg]) -> Result<(Matrix, MatrixHandle)> {
+        let output_handle: MatrixHandle = self.run_kernel_resident(kernel, args)?;
+
+        Ok((self.fetch(output_handle)?, output_handle))
+    }
+
+    /// Run custom kernel on args in declared order, leaving the result in backend memory until fetched
+    ///
+    /// Argument counts, types and shapes are checked against the declared signature before the kernel is enqueued
+    pub fn run_kernel_resident(&mut self, kernel: &CustomKernel, args: &[KernelArg]) -> Result<MatrixHandle> {
+        // Kernel indices are only meaningful to the backend that compiled them
+        if kernel.calculator_id != self.id {
+            return Err(LAError::ArgumentError)
+        }
+
+        let (output_rows, output_cols, work_sizes) = kernel.signature.launch_params(args)?;
+
+        let bound_args: Vec<BoundArg> = args
+            .iter()
+            .map(| arg | match *arg {
+                KernelArg::Float(value) => Ok(BoundArg::Float(value)),
+                KernelArg::Int(value) => Ok(BoundArg::Int(value)),
+                KernelArg::Matrix(handle) => self.check(handle).map(|_| BoundArg::Matrix(handle.index))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.backend.execute(
+            kernel.kernel_index,
+            bound_args,
+            self.next_slot(),
+            output_rows,
+            output_cols,
+            work_sizes
+        )?;
+
+        Ok(self.fill_next_slot(Slot::Resident, output_rows, output_cols))
     }
 
     /// Compile and store a custom kernel and build/store a closure to execute said kernel
+    #[deprecated(note = "use load_kernel with a KernelBuilder")]
     pub unsafe fn load_custom_fn(
         &mut self,
         program_source: &str,
@@ -112,33 +710,31 @@ impl Calculator {
         parameter_fn: ParameterFunction
     ) -> Result<usize>
     {
-        let new_kernel_index: usize = self.memory_handler.new_kernel(program_source, kernel_name)?;
+        let new_kernel_index: usize = self.backend.new_kernel(program_source, kernel_name, "")?;
         self.custom_idcs.push(new_kernel_index);
 
         self.params_customs.push(parameter_fn);
 
         let new_custom: ResultFunction = Box::new(|
                 index: usize,
-                host: &mut MemoryHandler,
+                host: &mut dyn Backend,
                 input_floats: Option<Vec<f32>>,
                 input_ints: Option<Vec<i32>>,
                 input_mat_idcs: Vec<usize>,
+                output_idx: usize,
                 output_rows: usize,
                 output_cols: usize,
                 work_sizes: Vec<usize>
-            | -> Result<(Matrix, usize)>
+            | -> Result<()>
             {
-                let (output, output_idx) = host.execute_and_read(
+                host.execute(
                     index,
-                    input_floats,
-                    input_ints,
-                    input_mat_idcs,
+                    bind_in_order(input_floats, input_ints, input_mat_idcs),
+                    output_idx,
                     output_rows,
                     output_cols,
                     work_sizes
-                )?;
-
-                Ok((output, output_idx))
+                )
             }
         );
 
@@ -147,40 +743,51 @@ impl Calculator {
         return Ok(self.customs.len() - 1);
     }
 
-    /// Execute custom kernel via pre-generated closure
+    /// Execute custom kernel via pre-generated closure, reading the result back to the host
+    #[deprecated(note = "use run_kernel with a CustomKernel")]
     pub unsafe fn exec_custom_fn(
         &mut self,
         custom_index: usize,
         input_floats: Option<Vec<f32>>,
         input_ints: Option<Vec<i32>>,
-        input_mat_idcs: Vec<usize>
-    ) -> Result<(Matrix, usize)>
+        input_handles: Vec<MatrixHandle>
+    ) -> Result<(Matrix, MatrixHandle)>
     {
-        let input_matrices: Vec<&Matrix> = input_mat_idcs
-            .clone()
-            .into_iter()
-            .map(| idx | &self.matrices[idx])
-            .collect::<Vec<_>>();
+        if custom_index >= self.customs.len() {
+            return Err(LAError::IndexError)
+        }
+
+        for handle in input_handles.iter() {
+            self.cache(*handle)?;
+        }
+
+        let input_matrices: Vec<&Matrix> = input_handles
+            .iter()
+            .map(| handle | self.host_copy(*handle))
+            .collect::<Result<Vec<_>>>()?;
 
         let (output_rows, output_cols, work_sizes) = self.params_customs[custom_index](input_matrices)?;
 
-        let (output_matrix, output_idx) = self.customs[custom_index](
+        let input_mat_idcs: Vec<usize> = input_handles
+            .iter()
+            .map(| handle | handle.index)
+            .collect();
+        let output_idx: usize = self.next_slot();
+
+        self.customs[custom_index](
             self.custom_idcs[custom_index],
-            &mut self.memory_handler,
+            self.backend.as_mut(),
             input_floats,
             input_ints,
             input_mat_idcs,
+            output_idx,
             output_rows,
             output_cols,
             work_sizes
         )?;
 
-        self.matrices.push(output_matrix.clone());
+        let output_handle: MatrixHandle = self.fill_next_slot(Slot::Resident, output_rows, output_cols);
 
-        if output_idx != (self.matrices.len() - 1) {
-            return Err(LAError::MemoryError);
-        }
-
-        Ok((output_matrix, output_idx))
+        Ok((self.fetch(output_handle)?, output_handle))
     }
-}
\ No newline at end of file
+}
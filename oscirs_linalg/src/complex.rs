@@ -0,0 +1,156 @@
+//! Complex number type usable as a Matrix element type
+
+use std::fmt;
+use std::iter;
+use std::ops;
+
+use crate::scalar::{
+    Real,
+    Scalar
+};
+
+/// Complex number with real and imaginary parts (defaults to f32 parts)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T = f32> {
+    pub re: T, // Real part
+    pub im: T // Imaginary part
+}
+
+impl<T: Real> Complex<T> {
+    /// Create new complex number from real and imaginary parts
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+
+    /// Get imaginary unit
+    pub fn i() -> Complex<T> {
+        Complex { re: T::zero(), im: T::one() }
+    }
+
+    /// Get complex conjugate
+    pub fn conj(self) -> Complex<T> {
+        Complex { re: self.re, im: -self.im }
+    }
+
+    /// Get squared magnitude
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Get magnitude
+    pub fn norm(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+}
+
+impl<T: Real> From<T> for Complex<T> {
+    fn from(re: T) -> Complex<T> {
+        Complex { re, im: T::zero() }
+    }
+}
+
+impl<T: Real + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.im < T::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        }
+        else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+// Add Complex to Complex
+impl<T: Real> ops::Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+// Subtract Complex from Complex
+impl<T: Real> ops::Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+// Multiply Complex by Complex
+impl<T: Real> ops::Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Complex<T> {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re
+        }
+    }
+}
+
+// Divide Complex by Complex
+impl<T: Real> ops::Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: Complex<T>) -> Complex<T> {
+        let denominator: T = rhs.norm_sqr();
+        let numerator: Complex<T> = self * rhs.conj();
+
+        Complex { re: numerator.re / denominator, im: numerator.im / denominator }
+    }
+}
+
+// Negate Complex
+impl<T: Real> ops::Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+impl<T: Real> ops::AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Complex<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Real> ops::SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Complex<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Real> ops::MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Complex<T>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Real> ops::DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Complex<T>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Real> iter::Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::default(), |acc, value| acc + value)
+    }
+}
+
+impl<T: Real> Scalar for Complex<T> {
+    fn zero() -> Complex<T> {
+        Complex { re: T::zero(), im: T::zero() }
+    }
+
+    fn one() -> Complex<T> {
+        Complex { re: T::one(), im: T::zero() }
+    }
+
+    fn conj(self) -> Complex<T> {
+        Complex::conj(self)
+    }
+}
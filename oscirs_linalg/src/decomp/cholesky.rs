@@ -0,0 +1,117 @@
+//! Cholesky decomposition of symmetric positive definite matrices
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+
+/// Cholesky factorization of a symmetric positive definite matrix such that A = L * L^T
+#[derive(Debug, Clone)]
+pub struct Cholesky<T = f32> {
+    lower: Matrix<T> // Lower triangular factor L
+}
+
+impl<T: Real> Cholesky<T> {
+    /// Factorize square matrix using only its lower triangle
+    pub fn new(matrix: &Matrix<T>) -> Result<Cholesky<T>> {
+        if matrix.rows != matrix.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n: usize = matrix.rows;
+        let mut l: Vec<T> = vec![T::zero(); n * n];
+
+        for col in 0..n {
+            // Diagonal entry
+            let diag_sq: T = matrix.data[col * n + col]
+                - (0..col).map(|k| l[col * n + k] * l[col * n + k]).sum::<T>();
+
+            if diag_sq <= T::zero() || !diag_sq.is_finite() {
+                return Err(LAError::NotPositiveDefiniteError)
+            }
+
+            let diag: T = diag_sq.sqrt();
+            l[col * n + col] = diag;
+
+            // Entries below the diagonal
+            for row in (col + 1)..n {
+                let dot: T = (0..col).map(|k| l[row * n + k] * l[col * n + k]).sum();
+                l[row * n + col] = (matrix.data[row * n + col] - dot) / diag;
+            }
+        }
+
+        let lower: Matrix<T> = Matrix { data: l, rows: n, cols: n };
+
+        Ok(Cholesky { lower })
+    }
+
+    /// Get lower triangular factor L
+    pub fn get_l(&self) -> Matrix<T> {
+        self.lower.clone()
+    }
+
+    /// Get natural logarithm of the determinant of the factorized matrix
+    pub fn log_determinant(&self) -> T {
+        let n: usize = self.lower.rows;
+
+        T::from_f64(2.0) * (0..n).map(|idx| self.lower.data[idx * n + idx].ln()).sum::<T>()
+    }
+
+    /// Solve A * X = B for X, where B may have several columns
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let n: usize = self.lower.rows;
+        if rhs.rows != n {
+            return Err(LAError::SizeError)
+        }
+
+        let n_rhs: usize = rhs.cols;
+        let l: &Vec<T> = &self.lower.data;
+        let mut x_data: Vec<T> = rhs.data.clone();
+
+        // Forward substitution with L
+        for row in 0..n {
+            for k in 0..row {
+                let factor: T = l[row * n + k];
+                for col in 0..n_rhs {
+                    let update: T = factor * x_data[k * n_rhs + col];
+                    x_data[row * n_rhs + col] -= update;
+                }
+            }
+
+            let pivot: T = l[row * n + row];
+            for col in 0..n_rhs {
+                x_data[row * n_rhs + col] /= pivot;
+            }
+        }
+
+        // Back substitution with L^T
+        for row in (0..n).rev() {
+            for k in (row + 1)..n {
+                let factor: T = l[k * n + row];
+                for col in 0..n_rhs {
+                    let update: T = factor * x_data[k * n_rhs + col];
+                    x_data[row * n_rhs + col] -= update;
+                }
+            }
+
+            let pivot: T = l[row * n + row];
+            for col in 0..n_rhs {
+                x_data[row * n_rhs + col] /= pivot;
+            }
+        }
+
+        Ok(Matrix { data: x_data, rows: n, cols: n_rhs })
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return Cholesky decomposition of self (only the lower triangle of self is read)
+    pub fn cholesky(&self) -> Result<Cholesky<T>> {
+        Cholesky::new(self)
+    }
+
+    /// Solve self * X = B for X using Cholesky decomposition, where self is symmetric positive definite
+    pub fn solve_spd(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.cholesky()?.solve(rhs)
+    }
+}
@@ -0,0 +1,396 @@
+//! Eigenvalue decompositions for symmetric and general square matrices
+
+use crate::Result;
+use crate::err::LAError;
+use crate::complex::Complex;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+
+/// Maximum number of Jacobi sweeps before giving up
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Maximum number of QR iterations spent on a single eigenvalue before giving up
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// Eigendecomposition of a symmetric matrix such that A = V * diag(values) * V^T
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T = f32> {
+    values: Vec<T>, // Eigenvalues in ascending order
+    vectors: Matrix<T> // Orthonormal eigenvectors stored as columns
+}
+
+impl<T: Real> SymmetricEigen<T> {
+    /// Decompose square matrix using cyclic Jacobi rotations (only the lower triangle is read)
+    pub fn new(matrix: &Matrix<T>) -> Result<SymmetricEigen<T>> {
+        if matrix.rows != matrix.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n: usize = matrix.rows;
+
+        // Build symmetric working copy from lower triangle
+        let mut a: Vec<T> = vec![T::zero(); n * n];
+        for row in 0..n {
+            for col in 0..=row {
+                a[row * n + col] = matrix.data[row * n + col];
+                a[col * n + row] = matrix.data[row * n + col];
+            }
+        }
+
+        let mut v: Vec<T> = vec![T::zero(); n * n];
+        for idx in 0..n {
+            v[idx * n + idx] = T::one();
+        }
+
+        let frob_norm: T = a.iter().map(|&value| value * value).sum::<T>().sqrt();
+
+        let mut converged: bool = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off_norm: T = (0..n)
+                .flat_map(|row| (0..n).filter(move |&col| col != row).map(move |col| (row, col)))
+                .map(|(row, col)| a[row * n + col] * a[row * n + col])
+                .sum::<T>()
+                .sqrt();
+
+            if off_norm <= T::EPSILON * frob_norm {
+                converged = true;
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let apq: T = a[p * n + q];
+                    if apq == T::zero() {
+                        continue;
+                    }
+
+                    // Rotation angle zeroing a[p, q]
+                    let theta: T = (a[q * n + q] - a[p * n + p]) / (T::from_f64(2.0) * apq);
+                    let t: T = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c: T = T::one() / (t * t + T::one()).sqrt();
+                    let s: T = t * c;
+
+                    // A = A * J
+                    for k in 0..n {
+                        let akp: T = a[k * n + p];
+                        let akq: T = a[k * n + q];
+                        a[k * n + p] = c * akp - s * akq;
+                        a[k * n + q] = s * akp + c * akq;
+                    }
+
+                    // A = J^T * A
+                    for k in 0..n {
+                        let apk: T = a[p * n + k];
+                        let aqk: T = a[q * n + k];
+                        a[p * n + k] = c * apk - s * aqk;
+                        a[q * n + k] = s * apk + c * aqk;
+                    }
+
+                    a[p * n + q] = T::zero();
+                    a[q * n + p] = T::zero();
+
+                    // V = V * J
+                    for k in 0..n {
+                        let vkp: T = v[k * n + p];
+                        let vkq: T = v[k * n + q];
+                        v[k * n + p] = c * vkp - s * vkq;
+                        v[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        if !converged {
+            return Err(LAError::ConvergenceError)
+        }
+
+        // Sort eigenpairs by ascending eigenvalue
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]));
+
+        let values: Vec<T> = order.iter().map(|&idx| a[idx * n + idx]).collect();
+        let mut vectors_data: Vec<T> = vec![T::zero(); n * n];
+        for (new_col, &old_col) in order.iter().enumerate() {
+            for row in 0..n {
+                vectors_data[row * n + new_col] = v[row * n + old_col];
+            }
+        }
+
+        let vectors: Matrix<T> = Matrix { data: vectors_data, rows: n, cols: n };
+
+        Ok(SymmetricEigen { values, vectors })
+    }
+
+    /// Get eigenvalues in ascending order
+    pub fn get_values(&self) -> Vec<T> {
+        self.values.clone()
+    }
+
+    /// Get matrix whose columns are the eigenvectors matching get_values()
+    pub fn get_vectors(&self) -> Matrix<T> {
+        self.vectors.clone()
+    }
+}
+
+// Reduce square matrix to upper Hessenberg form by stabilized elimination (private function)
+fn hessenberg<T: Real>(a: &mut [T], n: usize) {
+    for m in 1..n.saturating_sub(1) {
+        // Find pivot in column m - 1
+        let mut pivot: T = T::zero();
+        let mut pivot_row: usize = m;
+        for row in m..n {
+            if a[row * n + m - 1].abs() > pivot.abs() {
+                pivot = a[row * n + m - 1];
+                pivot_row = row;
+            }
+        }
+
+        // Similarity transform swapping rows and columns
+        if pivot_row != m {
+            for col in (m - 1)..n {
+                a.swap(pivot_row * n + col, m * n + col);
+            }
+            for row in 0..n {
+                a.swap(row * n + pivot_row, row * n + m);
+            }
+        }
+
+        if pivot == T::zero() {
+            continue;
+        }
+
+        for row in (m + 1)..n {
+            let factor: T = a[row * n + m - 1] / pivot;
+            if factor == T::zero() {
+                continue;
+            }
+
+            a[row * n + m - 1] = T::zero();
+            for col in m..n {
+                let update: T = factor * a[m * n + col];
+                a[row * n + col] -= update;
+            }
+            for k in 0..n {
+                let update: T = factor * a[k * n + row];
+                a[k * n + m] += update;
+            }
+        }
+    }
+}
+
+// Get magnitude of a with the sign of b (private function)
+fn with_sign<T: Real>(a: T, b: T) -> T {
+    if b >= T::zero() { a.abs() } else { -a.abs() }
+}
+
+// Find all eigenvalues of upper Hessenberg matrix with shifted QR iterations (private function)
+fn hessenberg_eigenvalues<T: Real>(a: &mut [T], n: usize) -> Result<Vec<Complex<T>>> {
+    let mut values: Vec<Complex<T>> = vec![Complex::default(); n];
+
+    let mut a_norm: T = T::zero();
+    for row in 0..n {
+        for col in row.saturating_sub(1)..n {
+            a_norm += a[row * n + col].abs();
+        }
+    }
+
+    let idx = |row: usize, col: usize| row * n + col;
+
+    // Accumulated exceptional shifts
+    let mut shift: T = T::zero();
+
+    let mut nn: isize = n as isize - 1;
+    while nn >= 0 {
+        let mut iterations: usize = 0;
+        loop {
+            let u_nn: usize = nn as usize;
+
+            // Look for a single small subdiagonal element to split the matrix
+            let mut l: usize = u_nn;
+            while l > 0 {
+                let mut s: T = a[idx(l - 1, l - 1)].abs() + a[idx(l, l)].abs();
+                if s == T::zero() {
+                    s = a_norm;
+                }
+                if a[idx(l, l - 1)].abs() <= T::EPSILON * s {
+                    a[idx(l, l - 1)] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x: T = a[idx(u_nn, u_nn)];
+
+            if l == u_nn {
+                // One root found
+                values[u_nn] = Complex::new(x + shift, T::zero());
+                nn -= 1;
+                break;
+            }
+
+            let mut y: T = a[idx(u_nn - 1, u_nn - 1)];
+            let mut w: T = a[idx(u_nn, u_nn - 1)] * a[idx(u_nn - 1, u_nn)];
+
+            if l == u_nn - 1 {
+                // Two roots found
+                let p: T = T::from_f64(0.5) * (y - x);
+                let q: T = p * p + w;
+                let z: T = q.abs().sqrt();
+                x += shift;
+
+                if q >= T::zero() {
+                    // Real pair
+                    let z: T = p + with_sign(z, p);
+                    values[u_nn - 1] = Complex::new(x + z, T::zero());
+                    values[u_nn] = if z != T::zero() { Complex::new(x - w / z, T::zero()) } else { Complex::new(x + z, T::zero()) };
+                }
+                else {
+                    // Complex conjugate pair
+                    values[u_nn - 1] = Complex::new(x + p, z);
+                    values[u_nn] = Complex::new(x + p, -z);
+                }
+
+                nn -= 2;
+                break;
+            }
+
+            if iterations == MAX_QR_ITERATIONS {
+                return Err(LAError::ConvergenceError)
+            }
+
+            // Exceptional shift to break cycles
+            if iterations == 10 || iterations == 20 {
+                shift += x;
+                for diag in 0..=u_nn {
+                    a[idx(diag, diag)] -= x;
+                }
+                let s: T = a[idx(u_nn, u_nn - 1)].abs() + a[idx(u_nn - 1, u_nn - 2)].abs();
+                x = T::from_f64(0.75) * s;
+                y = x;
+                w = -T::from_f64(0.4375) * s * s;
+            }
+            iterations += 1;
+
+            // Form double shift and look for two consecutive small subdiagonal elements
+            let mut m: usize = u_nn - 2;
+            let (mut p, mut q, mut r): (T, T, T);
+            loop {
+                let z: T = a[idx(m, m)];
+                let r_shift: T = x - z;
+                let s_shift: T = y - z;
+                p = (r_shift * s_shift - w) / a[idx(m + 1, m)] + a[idx(m, m + 1)];
+                q = a[idx(m + 1, m + 1)] - z - r_shift - s_shift;
+                r = a[idx(m + 2, m + 1)];
+
+                let s: T = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+
+                if m == l {
+                    break;
+                }
+
+                let u: T = a[idx(m, m - 1)].abs() * (q.abs() + r.abs());
+                let v: T = p.abs() * (a[idx(m - 1, m - 1)].abs() + z.abs() + a[idx(m + 1, m + 1)].abs());
+                if u <= T::EPSILON * v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m..(u_nn - 1) {
+                a[idx(i + 2, i)] = T::zero();
+                if i != m {
+                    a[idx(i + 2, i - 1)] = T::zero();
+                }
+            }
+
+            // Double shift QR step on rows l..=nn and columns m..=nn
+            for k in m..u_nn {
+                if k != m {
+                    p = a[idx(k, k - 1)];
+                    q = a[idx(k + 1, k - 1)];
+                    r = if k + 1 != u_nn { a[idx(k + 2, k - 1)] } else { T::zero() };
+
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != T::zero() {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+
+                let s: T = with_sign((p * p + q * q + r * r).sqrt(), p);
+                if s == T::zero() {
+                    continue;
+                }
+
+                if k == m {
+                    if l != m {
+                        a[idx(k, k - 1)] = -a[idx(k, k - 1)];
+                    }
+                }
+                else {
+                    a[idx(k, k - 1)] = -s * x;
+                }
+
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z: T = r / s;
+                q /= p;
+                r /= p;
+
+                // Row modification
+                for j in k..=u_nn {
+                    let mut pr: T = a[idx(k, j)] + q * a[idx(k + 1, j)];
+                    if k + 1 != u_nn {
+                        pr += r * a[idx(k + 2, j)];
+                        a[idx(k + 2, j)] -= pr * z;
+                    }
+                    a[idx(k + 1, j)] -= pr * y;
+                    a[idx(k, j)] -= pr * x;
+                }
+
+                // Column modification
+                let i_max: usize = u_nn.min(k + 3);
+                for i in l..=i_max {
+                    let mut pc: T = x * a[idx(i, k)] + y * a[idx(i, k + 1)];
+                    if k + 1 != u_nn {
+                        pc += z * a[idx(i, k + 2)];
+                        a[idx(i, k + 2)] -= pc * r;
+                    }
+                    a[idx(i, k + 1)] -= pc * q;
+                    a[idx(i, k)] -= pc;
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return eigendecomposition of symmetric self (only the lower triangle of self is read)
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>> {
+        SymmetricEigen::new(self)
+    }
+
+    /// Return all eigenvalues of square self sorted by real then imaginary part
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<T>>> {
+        if self.rows != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n: usize = self.rows;
+        let mut a: Vec<T> = self.data.clone();
+
+        hessenberg(&mut a, n);
+        let mut values: Vec<Complex<T>> = hessenberg_eigenvalues(&mut a, n)?;
+
+        values.sort_by(|left, right| left.re.total_cmp(&right.re).then(left.im.total_cmp(&right.im)));
+
+        Ok(values)
+    }
+}
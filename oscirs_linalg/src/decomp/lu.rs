@@ -0,0 +1,223 @@
+//! LU decomposition with partial pivoting
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+
+/// LU factorization of a square matrix such that P * A = L * U
+#[derive(Debug, Clone)]
+pub struct LU<T = f32> {
+    factors: Matrix<T>, // Unit lower triangle holds L, upper triangle holds U
+    perm: Vec<usize>, // perm[i] is the row of A moved to row i
+    perm_sign: T, // Sign of the row permutation (-1 for an odd number of swaps)
+    singular: bool // Whether a pivot vanished during elimination
+}
+
+impl<T: Real> LU<T> {
+    /// Factorize square matrix using Gaussian elimination with partial pivoting
+    pub fn new(matrix: &Matrix<T>) -> Result<LU<T>> {
+        if matrix.rows != matrix.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n: usize = matrix.rows;
+        let mut lu: Vec<T> = matrix.data.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut perm_sign: T = T::one();
+        let mut singular: bool = false;
+
+        // Pivots smaller than this are treated as zero
+        let max_abs: T = lu.iter().fold(T::zero(), |acc, value| acc.max(value.abs()));
+        let tolerance: T = T::from_f64(n as f64) * T::EPSILON * max_abs;
+
+        for k in 0..n {
+            // Find row with largest magnitude entry in column k
+            let mut pivot_row: usize = k;
+            let mut pivot_abs: T = lu[k * n + k].abs();
+            for row in (k + 1)..n {
+                if lu[row * n + k].abs() > pivot_abs {
+                    pivot_row = row;
+                    pivot_abs = lu[row * n + k].abs();
+                }
+            }
+
+            // Move pivot row into place
+            if pivot_row != k {
+                for col in 0..n {
+                    lu.swap(k * n + col, pivot_row * n + col);
+                }
+                perm.swap(k, pivot_row);
+                perm_sign = -perm_sign;
+            }
+
+            // Column is already zero below the diagonal, nothing to eliminate
+            if pivot_abs <= tolerance {
+                singular = true;
+                for row in (k + 1)..n {
+                    lu[row * n + k] = T::zero();
+                }
+                continue;
+            }
+
+            let pivot: T = lu[k * n + k];
+            for row in (k + 1)..n {
+                let factor: T = lu[row * n + k] / pivot;
+                lu[row * n + k] = factor;
+
+                for col in (k + 1)..n {
+                    let update: T = factor * lu[k * n + col];
+                    lu[row * n + col] -= update;
+                }
+            }
+        }
+
+        let factors: Matrix<T> = Matrix::new(lu, n, n)?;
+
+        Ok(LU { factors, perm, perm_sign, singular })
+    }
+
+    /// Get unit lower triangular factor L
+    pub fn get_l(&self) -> Matrix<T> {
+        let n: usize = self.factors.rows;
+        let mut l_data: Vec<T> = vec![T::zero(); n * n];
+
+        for row in 0..n {
+            for col in 0..row {
+                l_data[row * n + col] = self.factors.data[row * n + col];
+            }
+            l_data[row * n + row] = T::one();
+        }
+
+        Matrix { data: l_data, rows: n, cols: n }
+    }
+
+    /// Get upper triangular factor U
+    pub fn get_u(&self) -> Matrix<T> {
+        let n: usize = self.factors.rows;
+        let mut u_data: Vec<T> = vec![T::zero(); n * n];
+
+        for row in 0..n {
+            for col in row..n {
+                u_data[row * n + col] = self.factors.data[row * n + col];
+            }
+        }
+
+        Matrix { data: u_data, rows: n, cols: n }
+    }
+
+    /// Get permutation matrix P
+    pub fn get_p(&self) -> Matrix<T> {
+        let n: usize = self.factors.rows;
+        let mut p_data: Vec<T> = vec![T::zero(); n * n];
+
+        for (row, &source_row) in self.perm.iter().enumerate() {
+            p_data[row * n + source_row] = T::one();
+        }
+
+        Matrix { data: p_data, rows: n, cols: n }
+    }
+
+    /// Get row permutation record (row i of P * A is row perm[i] of A)
+    pub fn get_perm(&self) -> Vec<usize> {
+        self.perm.clone()
+    }
+
+    /// Check whether the factorized matrix is singular to working precision
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Get determinant of the factorized matrix
+    pub fn determinant(&self) -> T {
+        if self.singular {
+            return T::zero()
+        }
+
+        let n: usize = self.factors.rows;
+
+        (0..n).fold(self.perm_sign, |acc, idx| acc * self.factors.data[idx * n + idx])
+    }
+
+    /// Solve A * X = B for X, where B may have several columns
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let n: usize = self.factors.rows;
+        if rhs.rows != n {
+            return Err(LAError::SizeError)
+        }
+        if self.singular {
+            return Err(LAError::SingularMatrixError)
+        }
+
+        let n_rhs: usize = rhs.cols;
+        let lu: &Vec<T> = &self.factors.data;
+
+        // Apply row permutation to right hand side
+        let mut x_data: Vec<T> = Vec::with_capacity(n * n_rhs);
+        for &source_row in &self.perm {
+            x_data.extend_from_slice(&rhs.data[source_row * n_rhs..(source_row + 1) * n_rhs]);
+        }
+
+        // Forward substitution with unit lower triangle
+        for row in 0..n {
+            for k in 0..row {
+                let factor: T = lu[row * n + k];
+                for col in 0..n_rhs {
+                    let update: T = factor * x_data[k * n_rhs + col];
+                    x_data[row * n_rhs + col] -= update;
+                }
+            }
+        }
+
+        // Back substitution with upper triangle
+        for row in (0..n).rev() {
+            for k in (row + 1)..n {
+                let factor: T = lu[row * n + k];
+                for col in 0..n_rhs {
+                    let update: T = factor * x_data[k * n_rhs + col];
+                    x_data[row * n_rhs + col] -= update;
+                }
+            }
+
+            let pivot: T = lu[row * n + row];
+            for col in 0..n_rhs {
+                x_data[row * n_rhs + col] /= pivot;
+            }
+        }
+
+        Ok(Matrix { data: x_data, rows: n, cols: n_rhs })
+    }
+
+    /// Get inverse of the factorized matrix
+    pub fn inverse(&self) -> Result<Matrix<T>> {
+        let n: usize = self.factors.rows;
+        let mut identity_data: Vec<T> = vec![T::zero(); n * n];
+        for idx in 0..n {
+            identity_data[idx * n + idx] = T::one();
+        }
+
+        self.solve(&Matrix { data: identity_data, rows: n, cols: n })
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return LU decomposition of self with partial pivoting
+    pub fn lu(&self) -> Result<LU<T>> {
+        LU::new(self)
+    }
+
+    /// Solve self * X = B for X using LU decomposition
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.lu()?.solve(rhs)
+    }
+
+    /// Return determinant of self using LU decomposition
+    pub fn determinant(&self) -> Result<T> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// Return inverse of self using LU decomposition
+    pub fn inverse(&self) -> Result<Matrix<T>> {
+        self.lu()?.inverse()
+    }
+}
@@ -0,0 +1,7 @@
+//! Module organizing all matrix factorizations and the solvers built on them
+
+pub mod lu;
+pub mod cholesky;
+pub mod eigen;
+pub mod svd;
+pub mod qr;
@@ -0,0 +1,190 @@
+//! QR decomposition through Householder reflections
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+
+/// QR factorization of a matrix such that A = Q * R
+#[derive(Debug, Clone)]
+pub struct QR<T = f32> {
+    reflected: Matrix<T>, // A after all reflections, upper triangle holds R
+    reflectors: Vec<Vec<T>> // Unit Householder vectors, reflector k acts on rows k..m
+}
+
+impl<T: Real> QR<T> {
+    /// Factorize matrix using Householder reflections
+    pub fn new(matrix: &Matrix<T>) -> QR<T> {
+        let rows: usize = matrix.rows;
+        let cols: usize = matrix.cols;
+        let n_reflectors: usize = rows.min(cols);
+
+        let mut a: Vec<T> = matrix.data.clone();
+        let mut reflectors: Vec<Vec<T>> = Vec::with_capacity(n_reflectors);
+
+        for k in 0..n_reflectors {
+            // Build reflector mapping column k below the diagonal onto e_k
+            let mut v: Vec<T> = (k..rows)
+                .map(|row| a[row * cols + k])
+                .collect();
+            let x_norm: T = v.iter().map(|&value| value * value).sum::<T>().sqrt();
+
+            if x_norm == T::zero() {
+                reflectors.push(vec![T::zero(); rows - k]);
+                continue;
+            }
+
+            // Choose sign to avoid cancellation
+            let alpha: T = if v[0] >= T::zero() { -x_norm } else { x_norm };
+            v[0] -= alpha;
+
+            let v_norm: T = v.iter().map(|&value| value * value).sum::<T>().sqrt();
+            for value in &mut v {
+                *value /= v_norm;
+            }
+
+            // Apply reflector to remaining columns
+            for col in k..cols {
+                let dot: T = (k..rows).map(|row| v[row - k] * a[row * cols + col]).sum();
+                for row in k..rows {
+                    a[row * cols + col] -= T::from_f64(2.0) * v[row - k] * dot;
+                }
+            }
+
+            reflectors.push(v);
+        }
+
+        let reflected: Matrix<T> = Matrix { data: a, rows, cols };
+
+        QR { reflected, reflectors }
+    }
+
+    /// Get thin orthogonal factor Q (rows x min(rows, cols))
+    pub fn get_q(&self) -> Matrix<T> {
+        let rows: usize = self.reflected.rows;
+        let n_cols: usize = self.reflectors.len();
+
+        // Start from leading columns of the identity
+        let mut q_data: Vec<T> = vec![T::zero(); rows * n_cols];
+        for idx in 0..n_cols {
+            q_data[idx * n_cols + idx] = T::one();
+        }
+
+        // Q = H_0 * H_1 * ... * H_(k-1), so apply reflectors in reverse order
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            for col in 0..n_cols {
+                let dot: T = (k..rows).map(|row| v[row - k] * q_data[row * n_cols + col]).sum();
+                for row in k..rows {
+                    q_data[row * n_cols + col] -= T::from_f64(2.0) * v[row - k] * dot;
+                }
+            }
+        }
+
+        Matrix { data: q_data, rows, cols: n_cols }
+    }
+
+    /// Get upper triangular factor R (min(rows, cols) x cols)
+    pub fn get_r(&self) -> Matrix<T> {
+        let cols: usize = self.reflected.cols;
+        let n_rows: usize = self.reflectors.len();
+        let mut r_data: Vec<T> = vec![T::zero(); n_rows * cols];
+
+        for row in 0..n_rows {
+            for col in row..cols {
+                r_data[row * cols + col] = self.reflected.data[row * cols + col];
+            }
+        }
+
+        Matrix { data: r_data, rows: n_rows, cols }
+    }
+
+    /// Get numerical rank from the diagonal of R
+    pub fn rank(&self) -> usize {
+        let tolerance: T = self.tolerance();
+
+        (0..self.reflectors.len())
+            .filter(|&idx| self.r_diag(idx).abs() > tolerance)
+            .count()
+    }
+
+    /// Return Q^T * B without forming Q
+    pub fn apply_qt(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let rows: usize = self.reflected.rows;
+        if rhs.rows != rows {
+            return Err(LAError::SizeError)
+        }
+
+        let n_rhs: usize = rhs.cols;
+        let mut b: Vec<T> = rhs.data.clone();
+
+        for (k, v) in self.reflectors.iter().enumerate() {
+            for col in 0..n_rhs {
+                let dot: T = (k..rows).map(|row| v[row - k] * b[row * n_rhs + col]).sum();
+                for row in k..rows {
+                    b[row * n_rhs + col] -= T::from_f64(2.0) * v[row - k] * dot;
+                }
+            }
+        }
+
+        Ok(Matrix { data: b, rows, cols: n_rhs })
+    }
+
+    /// Solve min ||A * X - B|| for X, where A has at least as many rows as columns and full column rank
+    pub fn least_squares(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let cols: usize = self.reflected.cols;
+        if self.reflected.rows < cols {
+            return Err(LAError::SizeError)
+        }
+        if self.rank() < cols {
+            return Err(LAError::RankDeficientError)
+        }
+
+        let qtb: Matrix<T> = self.apply_qt(rhs)?;
+        let n_rhs: usize = qtb.cols;
+
+        // Back substitution with leading square block of R
+        let mut x_data: Vec<T> = qtb.data[..cols * n_rhs].to_vec();
+        for row in (0..cols).rev() {
+            for k in (row + 1)..cols {
+                let factor: T = self.reflected.data[row * cols + k];
+                for col in 0..n_rhs {
+                    let update: T = factor * x_data[k * n_rhs + col];
+                    x_data[row * n_rhs + col] -= update;
+                }
+            }
+
+            let pivot: T = self.r_diag(row);
+            for col in 0..n_rhs {
+                x_data[row * n_rhs + col] /= pivot;
+            }
+        }
+
+        Ok(Matrix { data: x_data, rows: cols, cols: n_rhs })
+    }
+
+    // Get diagonal entry of R (private function)
+    fn r_diag(&self, idx: usize) -> T {
+        self.reflected.data[idx * self.reflected.cols + idx]
+    }
+
+    // Diagonal entries of R smaller than this are treated as zero (private function)
+    fn tolerance(&self) -> T {
+        let max_diag: T = (0..self.reflectors.len())
+            .fold(T::zero(), |acc, idx| acc.max(self.r_diag(idx).abs()));
+        let max_dim: usize = self.reflected.rows.max(self.reflected.cols);
+
+        T::from_f64(max_dim as f64) * T::EPSILON * max_diag
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return Householder QR decomposition of self
+    pub fn qr(&self) -> QR<T> {
+        QR::new(self)
+    }
+
+    /// Solve min ||self * X - B|| for X using QR decomposition
+    pub fn least_squares(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.qr().least_squares(rhs)
+    }
+}
@@ -0,0 +1,298 @@
+//! Singular value decomposition and the helpers derived from it
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+
+/// Maximum number of one-sided Jacobi sweeps before giving up
+const MAX_SVD_SWEEPS: usize = 100;
+
+/// Singular value decomposition of a matrix such that A = U * S * V^T
+#[derive(Debug, Clone)]
+pub struct SVD<T = f32> {
+    u: Matrix<T>, // Left singular vectors stored as columns
+    singular_values: Vec<T>, // Singular values in descending order
+    v: Matrix<T>, // Right singular vectors stored as columns
+    rows: usize, // Number of rows in decomposed matrix
+    cols: usize // Number of columns in decomposed matrix
+}
+
+impl<T: Real> SVD<T> {
+    /// Compute thin decomposition, where U is rows x k and V is cols x k for k = min(rows, cols)
+    pub fn new(matrix: &Matrix<T>) -> Result<SVD<T>> {
+        let rows: usize = matrix.rows;
+        let cols: usize = matrix.cols;
+
+        // Work on whichever of A and A^T has at least as many rows as columns
+        let tall: bool = rows >= cols;
+        let work: Matrix<T> = if tall { matrix.clone() } else { matrix.transpose() };
+        let (work_rows, work_cols) = (work.rows, work.cols);
+
+        let mut w: Vec<Vec<T>> = (0..work_cols)
+            .map(|col| (0..work_rows).map(|row| work.data[row * work_cols + col]).collect())
+            .collect();
+        let mut v: Vec<Vec<T>> = identity_columns(work_cols);
+
+        one_sided_jacobi(&mut w, &mut v)?;
+
+        // Singular values are the column norms after orthogonalization
+        let norms: Vec<T> = w.iter().map(|col| dot(col, col).sqrt()).collect();
+        let mut order: Vec<usize> = (0..work_cols).collect();
+        order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+        let singular_values: Vec<T> = order.iter().map(|&idx| norms[idx]).collect();
+        let tolerance: T = default_tolerance(&singular_values, rows, cols);
+
+        // Normalize columns belonging to nonzero singular values
+        let mut left: Vec<Vec<T>> = Vec::with_capacity(work_cols);
+        for (&idx, &sigma) in order.iter().zip(&singular_values) {
+            if sigma <= tolerance {
+                break;
+            }
+            left.push(w[idx].iter().map(|&value| value / sigma).collect());
+        }
+        let left: Vec<Vec<T>> = complete_basis(left, work_rows, work_cols);
+        let right: Vec<Vec<T>> = order.iter().map(|&idx| v[idx].clone()).collect();
+
+        let left_mat: Matrix<T> = columns_to_matrix(&left, work_rows);
+        let right_mat: Matrix<T> = columns_to_matrix(&right, work_cols);
+
+        let (u, v) = if tall { (left_mat, right_mat) } else { (right_mat, left_mat) };
+
+        Ok(SVD { u, singular_values, v, rows, cols })
+    }
+
+    /// Compute full decomposition, where U is rows x rows and V is cols x cols
+    pub fn new_full(matrix: &Matrix<T>) -> Result<SVD<T>> {
+        let thin: SVD<T> = SVD::new(matrix)?;
+
+        let u: Matrix<T> = columns_to_matrix(&complete_basis(matrix_columns(&thin.u), thin.rows, thin.rows), thin.rows);
+        let v: Matrix<T> = columns_to_matrix(&complete_basis(matrix_columns(&thin.v), thin.cols, thin.cols), thin.cols);
+
+        Ok(SVD { u, v, ..thin })
+    }
+
+    /// Get left singular vectors as columns of U
+    pub fn get_u(&self) -> Matrix<T> {
+        self.u.clone()
+    }
+
+    /// Get singular values in descending order
+    pub fn get_singular_values(&self) -> Vec<T> {
+        self.singular_values.clone()
+    }
+
+    /// Get diagonal matrix S sized to match U and V
+    pub fn get_s(&self) -> Matrix<T> {
+        let s_rows: usize = self.u.cols;
+        let s_cols: usize = self.v.cols;
+        let mut s_data: Vec<T> = vec![T::zero(); s_rows * s_cols];
+
+        for (idx, &sigma) in self.singular_values.iter().enumerate() {
+            s_data[idx * s_cols + idx] = sigma;
+        }
+
+        Matrix { data: s_data, rows: s_rows, cols: s_cols }
+    }
+
+    /// Get right singular vectors as columns of V
+    pub fn get_v(&self) -> Matrix<T> {
+        self.v.clone()
+    }
+
+    /// Get number of singular values above tolerance (defaults to max(rows, cols) * eps * largest singular value)
+    pub fn rank(&self, tolerance: Option<T>) -> usize {
+        let tolerance: T = tolerance
+            .unwrap_or_else(|| default_tolerance(&self.singular_values, self.rows, self.cols));
+
+        self.singular_values
+            .iter()
+            .filter(|&&sigma| sigma > tolerance)
+            .count()
+    }
+
+    /// Get 2-norm condition number (infinite for singular matrices)
+    pub fn cond(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > T::zero() => largest / smallest,
+            _ => T::INFINITY
+        }
+    }
+
+    /// Get Moore-Penrose pseudo-inverse, treating singular values below the default tolerance as zero
+    pub fn pinv(&self) -> Matrix<T> {
+        let rank: usize = self.rank(None);
+        let u_cols: usize = self.u.cols;
+        let v_cols: usize = self.v.cols;
+
+        // pinv = V * S^+ * U^T
+        let mut pinv_data: Vec<T> = vec![T::zero(); self.cols * self.rows];
+        for row in 0..self.cols {
+            for col in 0..self.rows {
+                pinv_data[row * self.rows + col] = (0..rank)
+                    .map(|k| self.v.data[row * v_cols + k] * self.u.data[col * u_cols + k] / self.singular_values[k])
+                    .sum();
+            }
+        }
+
+        Matrix { data: pinv_data, rows: self.cols, cols: self.rows }
+    }
+}
+
+// Get dot product of two equal length slices (private function)
+fn dot<T: Real>(left: &[T], right: &[T]) -> T {
+    left.iter().zip(right).map(|(&l, &r)| l * r).sum()
+}
+
+// Get default tolerance below which singular values are treated as zero (private function)
+fn default_tolerance<T: Real>(singular_values: &[T], rows: usize, cols: usize) -> T {
+    let largest: T = singular_values.first().copied().unwrap_or(T::zero());
+
+    T::from_f64(rows.max(cols) as f64) * T::EPSILON * largest
+}
+
+// Get columns of the identity matrix (private function)
+fn identity_columns<T: Real>(n: usize) -> Vec<Vec<T>> {
+    (0..n)
+        .map(|col| (0..n).map(|row| if row == col { T::one() } else { T::zero() }).collect())
+        .collect()
+}
+
+// Split matrix into column vectors (private function)
+fn matrix_columns<T: Real>(matrix: &Matrix<T>) -> Vec<Vec<T>> {
+    (0..matrix.cols)
+        .map(|col| (0..matrix.rows).map(|row| matrix.data[row * matrix.cols + col]).collect())
+        .collect()
+}
+
+// Assemble column vectors into a matrix (private function)
+fn columns_to_matrix<T: Real>(columns: &[Vec<T>], rows: usize) -> Matrix<T> {
+    let cols: usize = columns.len();
+    let mut data: Vec<T> = vec![T::zero(); rows * cols];
+
+    for (col, column) in columns.iter().enumerate() {
+        for (row, &value) in column.iter().enumerate() {
+            data[row * cols + col] = value;
+        }
+    }
+
+    Matrix { data, rows, cols }
+}
+
+// Orthogonalize columns of w against each other with Jacobi rotations, accumulating rotations in v (private function)
+fn one_sided_jacobi<T: Real>(w: &mut [Vec<T>], v: &mut [Vec<T>]) -> Result<()> {
+    let n: usize = w.len();
+
+    for _ in 0..MAX_SVD_SWEEPS {
+        let mut rotated: bool = false;
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha: T = dot(&w[p], &w[p]);
+                let beta: T = dot(&w[q], &w[q]);
+                let gamma: T = dot(&w[p], &w[q]);
+
+                if gamma == T::zero() || gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotation making columns p and q orthogonal
+                let zeta: T = (beta - alpha) / (T::from_f64(2.0) * gamma);
+                let t: T = zeta.signum() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                let c: T = T::one() / (t * t + T::one()).sqrt();
+                let s: T = t * c;
+
+                for vectors in [&mut *w, &mut *v] {
+                    let (head, tail) = vectors.split_at_mut(q);
+                    for (wp, wq) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        let old_p: T = *wp;
+                        *wp = c * old_p - s * *wq;
+                        *wq = s * old_p + c * *wq;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            return Ok(())
+        }
+    }
+
+    Err(LAError::ConvergenceError)
+}
+
+// Extend orthonormal columns of the given length to target count using the standard basis (private function)
+fn complete_basis<T: Real>(mut columns: Vec<Vec<T>>, length: usize, target: usize) -> Vec<Vec<T>> {
+    while columns.len() < target {
+        // Pick the basis vector with the largest component outside the current span
+        let mut best: Vec<T> = Vec::default();
+        let mut best_norm: T = -T::one();
+
+        for basis_idx in 0..length {
+            let mut candidate: Vec<T> = vec![T::zero(); length];
+            candidate[basis_idx] = T::one();
+
+            // Orthogonalize twice for numerical stability
+            for _ in 0..2 {
+                for column in &columns {
+                    let projection: T = dot(&candidate, column);
+                    for (value, basis_value) in candidate.iter_mut().zip(column) {
+                        *value -= projection * *basis_value;
+                    }
+                }
+            }
+
+            let norm: T = dot(&candidate, &candidate).sqrt();
+            if norm > best_norm {
+                best = candidate;
+                best_norm = norm;
+            }
+        }
+
+        columns.push(best.iter().map(|&value| value / best_norm).collect());
+    }
+
+    columns
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return thin singular value decomposition of self
+    pub fn svd(&self) -> Result<SVD<T>> {
+        SVD::new(self)
+    }
+
+    /// Return full singular value decomposition of self
+    pub fn full_svd(&self) -> Result<SVD<T>> {
+        SVD::new_full(self)
+    }
+
+    /// Return Moore-Penrose pseudo-inverse of self
+    pub fn pinv(&self) -> Result<Matrix<T>> {
+        Ok(self.svd()?.pinv())
+    }
+
+    /// Return numerical rank of self (tolerance defaults to max(rows, cols) * eps * largest singular value)
+    pub fn rank(&self, tolerance: Option<T>) -> Result<usize> {
+        Ok(self.svd()?.rank(tolerance))
+    }
+
+    /// Return 2-norm condition number of self
+    pub fn cond(&self) -> Result<T> {
+        Ok(self.svd()?.cond())
+    }
+
+    /// Return orthonormal basis for the null space of self as columns
+    pub fn null_space(&self) -> Result<Matrix<T>> {
+        let svd: SVD<T> = self.full_svd()?;
+        let rank: usize = svd.rank(None);
+
+        let null_columns: Vec<Vec<T>> = matrix_columns(&svd.v)
+            .into_iter()
+            .skip(rank)
+            .collect();
+
+        Ok(columns_to_matrix(&null_columns, self.cols))
+    }
+}
@@ -0,0 +1,169 @@
+//! Element-wise operations, broadcasting and axis reductions for Matrix
+
+use std::ops;
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::{
+    Real,
+    Scalar
+};
+
+/// Direction of an axis reduction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row, // Reduce each row to one value (rows x 1 result)
+    Col // Reduce each column to one value (1 x cols result)
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Apply function to every element, returning a new matrix
+    pub fn map<U: Scalar, F: Fn(T) -> U>(&self, element_fn: F) -> Matrix<U> {
+        let output_data: Vec<U> = self.data.iter().map(|&value| element_fn(value)).collect();
+
+        Matrix { data: output_data, rows: self.rows, cols: self.cols }
+    }
+
+    /// Combine each element with the matching element of rhs (must have same dimensions)
+    pub fn zip_map<F: Fn(T, T) -> T>(&self, rhs: &Matrix<T>, element_fn: F) -> Result<Matrix<T>> {
+        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
+            return Err(LAError::SizeError)
+        }
+
+        let output_data: Vec<T> = self.data.iter()
+            .zip(&rhs.data)
+            .map(|(&lhs_value, &rhs_value)| element_fn(lhs_value, rhs_value))
+            .collect();
+
+        Ok(Matrix { data: output_data, rows: self.rows, cols: self.cols })
+    }
+
+    /// Return element-wise (Hadamard) product of self and rhs
+    pub fn hadamard(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.zip_map(rhs, |lhs_value, rhs_value| lhs_value * rhs_value)
+    }
+
+    /// Combine each element with the matching element of rhs, where rhs is the same size, a 1 x cols row, a rows x 1 column or a 1 x 1 matrix
+    pub fn broadcast<F: Fn(T, T) -> T>(&self, rhs: &Matrix<T>, element_fn: F) -> Result<Matrix<T>> {
+        if (rhs.rows != self.rows && rhs.rows != 1) || (rhs.cols != self.cols && rhs.cols != 1) {
+            return Err(LAError::SizeError)
+        }
+
+        // Broadcast dimensions of rhs repeat their single row or column
+        let row_step: usize = if rhs.rows == self.rows { rhs.cols } else { 0 };
+        let col_step: usize = if rhs.cols == self.cols { 1 } else { 0 };
+
+        Ok(Matrix::from_fn(self.rows, self.cols, |row, col| {
+            element_fn(self.data[row * self.cols + col], rhs.data[row * row_step + col * col_step])
+        }))
+    }
+
+    /// Return sum of elements along axis
+    pub fn sum(&self, axis: Axis) -> Matrix<T> {
+        let sums: Vec<T> = self.reduce_axis(axis, |lane| lane.sum());
+
+        self.axis_matrix(axis, sums)
+    }
+
+    // Apply reduction to the values of every row or column (private function)
+    fn reduce_axis<U, F: Fn(&mut dyn Iterator<Item = T>) -> U>(&self, axis: Axis, reduction: F) -> Vec<U> {
+        match axis {
+            Axis::Row => (0..self.rows)
+                .map(|row| reduction(&mut self.data[row * self.cols..(row + 1) * self.cols].iter().copied()))
+                .collect(),
+            Axis::Col => (0..self.cols)
+                .map(|col| reduction(&mut (0..self.rows).map(|row| self.data[row * self.cols + col])))
+                .collect()
+        }
+    }
+
+    // Shape reduced values as a column (Axis::Row) or row (Axis::Col) matrix (private function)
+    fn axis_matrix<U>(&self, axis: Axis, values: Vec<U>) -> Matrix<U> {
+        let n_values: usize = values.len();
+
+        match axis {
+            Axis::Row => Matrix { data: values, rows: n_values, cols: 1 },
+            Axis::Col => Matrix { data: values, rows: 1, cols: n_values }
+        }
+    }
+
+    // Get number of values reduced into each result along axis (private function)
+    fn lane_len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Row => self.cols,
+            Axis::Col => self.rows
+        }
+    }
+}
+
+impl<T: Scalar + ops::Div<Output = T>> Matrix<T> {
+    /// Return element-wise quotient of self and rhs
+    pub fn div_elem(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.zip_map(rhs, |lhs_value, rhs_value| lhs_value / rhs_value)
+    }
+}
+
+impl<T: Scalar + PartialOrd> Matrix<T> {
+    /// Return smallest element along axis (errors if axis has no elements)
+    pub fn min(&self, axis: Axis) -> Result<Matrix<T>> {
+        if self.lane_len(axis) == 0 {
+            return Err(LAError::SizeError)
+        }
+
+        let mins: Vec<T> = self.reduce_axis(axis, |lane| {
+            lane.reduce(|acc, value| if value < acc { value } else { acc }).unwrap()
+        });
+
+        Ok(self.axis_matrix(axis, mins))
+    }
+
+    /// Return largest element along axis (errors if axis has no elements)
+    pub fn max(&self, axis: Axis) -> Result<Matrix<T>> {
+        if self.lane_len(axis) == 0 {
+            return Err(LAError::SizeError)
+        }
+
+        let maxes: Vec<T> = self.reduce_axis(axis, |lane| {
+            lane.reduce(|acc, value| if value > acc { value } else { acc }).unwrap()
+        });
+
+        Ok(self.axis_matrix(axis, maxes))
+    }
+
+    /// Return index of largest element along axis, taking the first on ties (errors if axis has no elements)
+    pub fn argmax(&self, axis: Axis) -> Result<Vec<usize>> {
+        if self.lane_len(axis) == 0 {
+            return Err(LAError::SizeError)
+        }
+
+        let indices: Vec<usize> = self.reduce_axis(axis, |lane| {
+            lane.enumerate()
+                .reduce(|acc, item| if item.1 > acc.1 { item } else { acc })
+                .unwrap()
+                .0
+        });
+
+        Ok(indices)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return mean of elements along axis (errors if axis has no elements)
+    pub fn mean(&self, axis: Axis) -> Result<Matrix<T>> {
+        if self.lane_len(axis) == 0 {
+            return Err(LAError::SizeError)
+        }
+
+        let count: T = T::from_f64(self.lane_len(axis) as f64);
+
+        Ok(self.sum(axis).map(|value| value / count))
+    }
+
+    /// Return Euclidean norm of each row or column along axis
+    pub fn norm(&self, axis: Axis) -> Matrix<T> {
+        let norms: Vec<T> = self.reduce_axis(axis, |lane| lane.map(|value| value * value).sum::<T>().sqrt());
+
+        self.axis_matrix(axis, norms)
+    }
+}
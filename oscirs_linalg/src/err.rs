@@ -9,13 +9,21 @@ use opencl3::error_codes::ClError;
 #[derive(Debug)]
 pub enum LAError {
     ArgumentError,
+    ConvergenceError,
     DataUpdateError,
+    DeviceNotFoundError,
     IndexError,
+    KernelBuildError { kernel_name: String, build_log: String },
     MatrixMismatchError,
     MemoryError,
+    NotPositiveDefiniteError,
+    RankDeficientError,
     ResizeError,
     ReturnValueError,
     SizeError,
+    SingularMatrixError,
+    StaleHandleError,
+    UnsupportedError,
     ClError(ClError),
     OtherError(String)
 }
@@ -25,20 +33,36 @@ impl fmt::Display for LAError {
         match self {
             LAError::ArgumentError =>
                 write!(f, "Too many or too few arguments provided"),
+            LAError::ConvergenceError =>
+                write!(f, "Iterative algorithm failed to converge"),
             LAError::DataUpdateError =>
                 write!(f, "New data vector has invalid length for current matrix dimensions"),
+            LAError::DeviceNotFoundError =>
+                write!(f, "No OpenCL device matches the requested selection"),
             LAError::IndexError =>
                 write!(f, "Index out of bounds for requested operation"),
+            LAError::KernelBuildError { kernel_name, build_log } =>
+                write!(f, "Failed to build OpenCL kernel {}:\n{}", kernel_name, build_log),
             LAError::MatrixMismatchError =>
                 write!(f, "Matrix dimensions are not consistent with data vector length"),
             LAError::MemoryError =>
                 write!(f, "Memory Calculator and Handler have inconsistent memory"),
+            LAError::NotPositiveDefiniteError =>
+                write!(f, "Matrix is not positive definite"),
+            LAError::RankDeficientError =>
+                write!(f, "Matrix is rank deficient to working precision"),
             LAError::ResizeError =>
                 write!(f, "Invalid dimensions for matrix resize operation"),
             LAError::ReturnValueError =>
                 write!(f, "No return value"),
             LAError::SizeError =>
                 write!(f, "Matrix dimensions not valid for requested operation"),
+            LAError::SingularMatrixError =>
+                write!(f, "Matrix is singular to working precision"),
+            LAError::StaleHandleError =>
+                write!(f, "Matrix handle refers to freed or overwritten memory, or to another calculator"),
+            LAError::UnsupportedError =>
+                write!(f, "Operation not supported by the active backend"),
             LAError::ClError(error) =>
                 write!(f, "{}", error),
             LAError::OtherError(error) =>
@@ -0,0 +1,232 @@
+//! Lazy matrix expressions over Calculator handles
+//!
+//! Expressions record operations without running them, so Calculator::eval can check every shape up front and
+//! fuse each elementwise subtree into a single OpenCL kernel
+
+use std::ops;
+
+use crate::Result;
+use crate::err::LAError;
+use crate::calculator::MatrixHandle;
+
+/// Name of generated fused elementwise kernels
+pub(crate) const FUSED_KERNEL_NAME: &str = "fused_elementwise";
+
+/// Lazily recorded matrix expression over matrices stored in a Calculator
+#[derive(Debug, Clone)]
+pub struct Expr {
+    pub(crate) node: Node // Operation producing this expression
+}
+
+/// Operation recorded by an expression node
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Input(MatrixHandle), // Matrix stored in Calculator memory
+    Add(Box<Expr>, Box<Expr>), // Elementwise sum
+    Sub(Box<Expr>, Box<Expr>), // Elementwise difference
+    Neg(Box<Expr>), // Elementwise negation
+    Scale(Box<Expr>, f32), // Product with scalar
+    MatMul(Box<Expr>, Box<Expr>) // Matrix product
+}
+
+impl Expr {
+    /// Create expression reading a stored matrix
+    pub fn input(handle: MatrixHandle) -> Expr {
+        Expr { node: Node::Input(handle) }
+    }
+
+    /// Create matrix product expression (same as the * operator)
+    pub fn mat_mul<R: Into<Expr>>(self, rhs: R) -> Expr {
+        Expr { node: Node::MatMul(Box::new(self), Box::new(rhs.into())) }
+    }
+
+    /// Check dimensions of every operation and return shape (rows, cols) of the result
+    pub fn shape(&self) -> Result<(usize, usize)> {
+        match &self.node {
+            Node::Input(handle) => Ok((handle.get_rows(), handle.get_cols())),
+            Node::Add(lhs, rhs) | Node::Sub(lhs, rhs) => {
+                let lhs_shape: (usize, usize) = lhs.shape()?;
+
+                if lhs_shape != rhs.shape()? {
+                    return Err(LAError::SizeError)
+                }
+
+                Ok(lhs_shape)
+            },
+            Node::Neg(operand) | Node::Scale(operand, _) => operand.shape(),
+            Node::MatMul(lhs, rhs) => {
+                let (lhs_rows, lhs_cols) = lhs.shape()?;
+                let (rhs_rows, rhs_cols) = rhs.shape()?;
+
+                if lhs_cols != rhs_rows {
+                    return Err(LAError::SizeError)
+                }
+
+                Ok((lhs_rows, rhs_cols))
+            }
+        }
+    }
+}
+
+/// Elementwise subtree lowered to the body of one fused kernel
+pub(crate) struct Fused<'a> {
+    pub(crate) leaves: Vec<&'a Expr>, // Inputs and products read by the kernel, in argument order
+    pub(crate) scalars: Vec<f32>, // Scalar kernel arguments, in argument order
+    body: String // OpenCL expression computing element i of the output
+}
+
+impl<'a> Fused<'a> {
+    /// Lower elementwise expression, stopping at inputs and matrix products
+    pub(crate) fn lower(expr: &'a Expr) -> Fused<'a> {
+        let mut fused: Fused<'a> = Fused { leaves: Vec::new(), scalars: Vec::new(), body: String::new() };
+        fused.body = fused.lower_node(expr);
+
+        fused
+    }
+
+    // Return OpenCL expression for node, recording leaves and scalars (private function)
+    fn lower_node(&mut self, expr: &'a Expr) -> String {
+        match &expr.node {
+            Node::Add(lhs, rhs) => format!("({} + {})", self.lower_node(lhs), self.lower_node(rhs)),
+            Node::Sub(lhs, rhs) => format!("({} - {})", self.lower_node(lhs), self.lower_node(rhs)),
+            Node::Neg(operand) => format!("(-{})", self.lower_node(operand)),
+            Node::Scale(operand, scalar) => {
+                let operand_body: String = self.lower_node(operand);
+                self.scalars.push(*scalar);
+
+                format!("(s{} * {})", self.scalars.len() - 1, operand_body)
+            },
+            Node::Input(handle) => {
+                // Repeated inputs share one kernel argument
+                let position: Option<usize> = self.leaves
+                    .iter()
+                    .position(|leaf| matches!(leaf.node, Node::Input(leaf_handle) if leaf_handle == *handle));
+
+                format!("x{}[i]", position.unwrap_or_else(|| self.push_leaf(expr)))
+            },
+            Node::MatMul(..) => format!("x{}[i]", self.push_leaf(expr))
+        }
+    }
+
+    // Record leaf read by the kernel and return its argument position (private function)
+    fn push_leaf(&mut self, expr: &'a Expr) -> usize {
+        self.leaves.push(expr);
+
+        self.leaves.len() - 1
+    }
+
+    /// Generate OpenCL source (output buffer, then scalars, then leaf buffers, matching Backend::execute)
+    pub(crate) fn source(&self) -> String {
+        let scalar_params: String = (0..self.scalars.len())
+            .map(|idx| format!("    const float s{},\n", idx))
+            .collect();
+
+        let leaf_params: Vec<String> = (0..self.leaves.len())
+            .map(|idx| format!("    const global float* x{}", idx))
+            .collect();
+
+        format!(
+            "kernel void {} (\n    global float* c,\n{}{}\n) {{\n    const int i = get_global_id(0);\n    c[i] = {};\n}}\n",
+            FUSED_KERNEL_NAME,
+            scalar_params,
+            leaf_params.join(",\n"),
+            self.body
+        )
+    }
+}
+
+impl From<MatrixHandle> for Expr {
+    fn from(handle: MatrixHandle) -> Expr {
+        Expr::input(handle)
+    }
+}
+
+// Add expressions elementwise
+impl<R: Into<Expr>> ops::Add<R> for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: R) -> Expr {
+        Expr { node: Node::Add(Box::new(self), Box::new(rhs.into())) }
+    }
+}
+
+// Subtract expressions elementwise
+impl<R: Into<Expr>> ops::Sub<R> for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: R) -> Expr {
+        Expr { node: Node::Sub(Box::new(self), Box::new(rhs.into())) }
+    }
+}
+
+// Multiply expressions as matrices
+impl<R: Into<Expr>> ops::Mul<R> for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: R) -> Expr {
+        self.mat_mul(rhs)
+    }
+}
+
+// Negate expression
+impl ops::Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        Expr { node: Node::Neg(Box::new(self)) }
+    }
+}
+
+// Scale expression by scalar
+impl ops::Mul<f32> for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: f32) -> Expr {
+        Expr { node: Node::Scale(Box::new(self), rhs) }
+    }
+}
+
+// Add stored matrix and expression elementwise
+impl<R: Into<Expr>> ops::Add<R> for MatrixHandle {
+    type Output = Expr;
+
+    fn add(self, rhs: R) -> Expr {
+        Expr::input(self) + rhs
+    }
+}
+
+// Subtract expression from stored matrix elementwise
+impl<R: Into<Expr>> ops::Sub<R> for MatrixHandle {
+    type Output = Expr;
+
+    fn sub(self, rhs: R) -> Expr {
+        Expr::input(self) - rhs
+    }
+}
+
+// Multiply stored matrix and expression as matrices
+impl<R: Into<Expr>> ops::Mul<R> for MatrixHandle {
+    type Output = Expr;
+
+    fn mul(self, rhs: R) -> Expr {
+        Expr::input(self) * rhs
+    }
+}
+
+// Negate stored matrix
+impl ops::Neg for MatrixHandle {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        -Expr::input(self)
+    }
+}
+
+// Scale stored matrix by scalar
+impl ops::Mul<f32> for MatrixHandle {
+    type Output = Expr;
+
+    fn mul(self, rhs: f32) -> Expr {
+        Expr::input(self) * rhs
+    }
+}
@@ -0,0 +1,303 @@
+//! Krylov subspace solvers: conjugate gradient, BiCGSTAB and restarted GMRES
+//!
+//! Every solver returns the final iterate together with a ConvergenceReport; check its converged flag
+
+use crate::Result;
+use crate::err::LAError;
+use crate::scalar::Real;
+
+use super::{
+    axpy,
+    dot,
+    norm,
+    ConvergenceReport,
+    SolverOptions
+};
+use super::operator::LinearOperator;
+use super::precond::{
+    Identity,
+    Preconditioner
+};
+
+// Validate dimensions and return starting iterate and right hand side norm (private function)
+fn setup<T: Real>(
+    operator: &dyn LinearOperator<T>,
+    rhs: &[T],
+    options: &SolverOptions<T>
+) -> Result<(Vec<T>, T)>
+{
+    let n: usize = operator.get_rows();
+    if operator.get_cols() != n || rhs.len() != n {
+        return Err(LAError::SizeError)
+    }
+
+    let x: Vec<T> = match &options.initial_guess {
+        Some(guess) if guess.len() != n => return Err(LAError::SizeError),
+        Some(guess) => guess.clone(),
+        None => vec![T::zero(); n]
+    };
+
+    let rhs_norm: T = norm(rhs);
+
+    // Zero right hand side is measured in absolute terms
+    let scale: T = if rhs_norm == T::zero() { T::one() } else { rhs_norm };
+
+    Ok((x, scale))
+}
+
+// Compute b - A * x (private function)
+fn residual<T: Real>(operator: &dyn LinearOperator<T>, rhs: &[T], x: &[T]) -> Result<Vec<T>> {
+    let ax: Vec<T> = operator.apply(x)?;
+
+    Ok(rhs.iter().zip(ax).map(|(&b, ax)| b - ax).collect())
+}
+
+/// Solve symmetric positive definite system A * x = b with preconditioned conjugate gradient
+pub fn cg<T: Real>(
+    operator: &dyn LinearOperator<T>,
+    rhs: &[T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &SolverOptions<T>
+) -> Result<(Vec<T>, ConvergenceReport<T>)>
+{
+    let precond: &dyn Preconditioner<T> = preconditioner.unwrap_or(&Identity);
+    let (mut x, scale) = setup(operator, rhs, options)?;
+
+    let mut r: Vec<T> = residual(operator, rhs, &x)?;
+    let mut history: Vec<T> = vec![norm(&r) / scale];
+    let mut converged: bool = history[0] <= options.tolerance;
+    let mut iterations: usize = 0;
+
+    let mut z: Vec<T> = precond.apply(&r);
+    let mut p: Vec<T> = z.clone();
+    let mut rz: T = dot(&r, &z);
+
+    while !converged && iterations < options.max_iterations {
+        let ap: Vec<T> = operator.apply(&p)?;
+        let p_ap: T = dot(&p, &ap);
+        if p_ap == T::zero() {
+            break;
+        }
+
+        let alpha: T = rz / p_ap;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        iterations += 1;
+
+        let relative: T = norm(&r) / scale;
+        history.push(relative);
+        if relative <= options.tolerance {
+            converged = true;
+            break;
+        }
+
+        z = precond.apply(&r);
+        let rz_new: T = dot(&r, &z);
+        let beta: T = rz_new / rz;
+        rz = rz_new;
+
+        for (p_value, &z_value) in p.iter_mut().zip(&z) {
+            *p_value = z_value + beta * *p_value;
+        }
+    }
+
+    Ok((x, ConvergenceReport { converged, iterations, residual_history: history }))
+}
+
+/// Solve general system A * x = b with right-preconditioned BiCGSTAB
+pub fn bicgstab<T: Real>(
+    operator: &dyn LinearOperator<T>,
+    rhs: &[T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &SolverOptions<T>
+) -> Result<(Vec<T>, ConvergenceReport<T>)>
+{
+    let precond: &dyn Preconditioner<T> = preconditioner.unwrap_or(&Identity);
+    let (mut x, scale) = setup(operator, rhs, options)?;
+    let n: usize = x.len();
+
+    let mut r: Vec<T> = residual(operator, rhs, &x)?;
+    let r_hat: Vec<T> = r.clone();
+    let mut history: Vec<T> = vec![norm(&r) / scale];
+    let mut converged: bool = history[0] <= options.tolerance;
+    let mut iterations: usize = 0;
+
+    let mut rho: T = T::one();
+    let mut alpha: T = T::one();
+    let mut omega: T = T::one();
+    let mut v: Vec<T> = vec![T::zero(); n];
+    let mut p: Vec<T> = vec![T::zero(); n];
+
+    while !converged && iterations < options.max_iterations {
+        let rho_new: T = dot(&r_hat, &r);
+        if rho_new == T::zero() || omega == T::zero() {
+            // Method breakdown
+            break;
+        }
+
+        let beta: T = (rho_new / rho) * (alpha / omega);
+        for idx in 0..n {
+            p[idx] = r[idx] + beta * (p[idx] - omega * v[idx]);
+        }
+
+        let p_hat: Vec<T> = precond.apply(&p);
+        v = operator.apply(&p_hat)?;
+
+        let r_hat_v: T = dot(&r_hat, &v);
+        if r_hat_v == T::zero() {
+            break;
+        }
+        alpha = rho_new / r_hat_v;
+
+        let mut s: Vec<T> = r.clone();
+        axpy(-alpha, &v, &mut s);
+        iterations += 1;
+
+        let s_relative: T = norm(&s) / scale;
+        if s_relative <= options.tolerance {
+            axpy(alpha, &p_hat, &mut x);
+            history.push(s_relative);
+            converged = true;
+            break;
+        }
+
+        let s_hat: Vec<T> = precond.apply(&s);
+        let t: Vec<T> = operator.apply(&s_hat)?;
+
+        let t_t: T = dot(&t, &t);
+        omega = if t_t == T::zero() { T::zero() } else { dot(&t, &s) / t_t };
+
+        axpy(alpha, &p_hat, &mut x);
+        axpy(omega, &s_hat, &mut x);
+
+        r = s;
+        axpy(-omega, &t, &mut r);
+        rho = rho_new;
+
+        let relative: T = norm(&r) / scale;
+        history.push(relative);
+        converged = relative <= options.tolerance;
+    }
+
+    Ok((x, ConvergenceReport { converged, iterations, residual_history: history }))
+}
+
+/// Solve general system A * x = b with right-preconditioned GMRES restarted every options.restart iterations
+pub fn gmres<T: Real>(
+    operator: &dyn LinearOperator<T>,
+    rhs: &[T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &SolverOptions<T>
+) -> Result<(Vec<T>, ConvergenceReport<T>)>
+{
+    if options.restart == 0 {
+        return Err(LAError::ArgumentError)
+    }
+
+    let precond: &dyn Preconditioner<T> = preconditioner.unwrap_or(&Identity);
+    let (mut x, scale) = setup(operator, rhs, options)?;
+    let m: usize = options.restart;
+
+    let r: Vec<T> = residual(operator, rhs, &x)?;
+    let mut history: Vec<T> = vec![norm(&r) / scale];
+    let mut converged: bool = history[0] <= options.tolerance;
+    let mut iterations: usize = 0;
+    let mut stagnated: bool = false;
+
+    while !converged && !stagnated && iterations < options.max_iterations {
+        let r: Vec<T> = residual(operator, rhs, &x)?;
+        let beta: T = norm(&r);
+        if beta == T::zero() {
+            converged = true;
+            break;
+        }
+
+        // Arnoldi basis, Hessenberg matrix (column-wise) and Givens rotations
+        let mut basis: Vec<Vec<T>> = vec![r.iter().map(|&value| value / beta).collect()];
+        let mut hessenberg: Vec<Vec<T>> = Vec::with_capacity(m);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(m);
+        let mut g: Vec<T> = vec![beta];
+
+        for j in 0..m {
+            let z: Vec<T> = precond.apply(&basis[j]);
+            let mut w: Vec<T> = operator.apply(&z)?;
+            let w_start_norm: T = norm(&w);
+
+            // Modified Gram-Schmidt orthogonalization
+            let mut h_col: Vec<T> = Vec::with_capacity(j + 2);
+            for basis_vec in &basis {
+                let h: T = dot(&w, basis_vec);
+                axpy(-h, basis_vec, &mut w);
+                h_col.push(h);
+            }
+            // Breakdown when nothing of the new direction survives rounding (basis spans an invariant subspace)
+            let w_norm: T = norm(&w);
+            let breakdown: bool = w_norm <= T::EPSILON * w_start_norm;
+            h_col.push(if breakdown { T::zero() } else { w_norm });
+
+            // Apply previous rotations to new column
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let upper: T = h_col[i];
+                let lower: T = h_col[i + 1];
+                h_col[i] = c * upper + s * lower;
+                h_col[i + 1] = c * lower - s * upper;
+            }
+
+            // New rotation eliminating the subdiagonal entry
+            let denominator: T = (h_col[j] * h_col[j] + h_col[j + 1] * h_col[j + 1]).sqrt();
+
+            // Zero diagonal means the operator is singular on the Krylov subspace, so truncate the basis before this
+            // column and stop (restarting would rebuild the same subspace)
+            if denominator <= T::EPSILON * w_start_norm {
+                iterations += 1;
+                history.push(g[j].abs() / scale);
+                stagnated = true;
+                break;
+            }
+
+            let (c, s) = (h_col[j] / denominator, h_col[j + 1] / denominator);
+            h_col[j] = denominator;
+            h_col[j + 1] = T::zero();
+            rotations.push((c, s));
+
+            let g_j: T = g[j];
+            g[j] = c * g_j;
+            g.push(-s * g_j);
+
+            hessenberg.push(h_col);
+            iterations += 1;
+
+            let relative: T = g[j + 1].abs() / scale;
+            history.push(relative);
+            converged = relative <= options.tolerance;
+
+            // Stop on convergence, iteration limit or lucky breakdown
+            if converged || iterations >= options.max_iterations || breakdown {
+                break;
+            }
+
+            basis.push(w.iter().map(|&value| value / w_norm).collect());
+        }
+
+        // Solve upper triangular system for Krylov coefficients
+        let k: usize = hessenberg.len();
+        let mut y: Vec<T> = g[..k].to_vec();
+        for row in (0..k).rev() {
+            for col in (row + 1)..k {
+                let update: T = hessenberg[col][row] * y[col];
+                y[row] -= update;
+            }
+            y[row] /= hessenberg[row][row];
+        }
+
+        // Update iterate with preconditioned combination of basis vectors
+        let mut correction: Vec<T> = vec![T::zero(); x.len()];
+        for (coefficient, basis_vec) in y.iter().zip(&basis) {
+            axpy(*coefficient, basis_vec, &mut correction);
+        }
+        let correction: Vec<T> = precond.apply(&correction);
+        axpy(T::one(), &correction, &mut x);
+    }
+
+    Ok((x, ConvergenceReport { converged, iterations, residual_history: history }))
+}
@@ -0,0 +1,53 @@
+//! Module organizing iterative solvers for large and sparse linear systems
+
+pub mod operator;
+pub mod precond;
+pub mod krylov;
+
+use crate::scalar::Real;
+
+/// Settings shared by all iterative solvers
+#[derive(Debug, Clone)]
+pub struct SolverOptions<T = f32> {
+    pub tolerance: T, // Target residual norm relative to the right hand side norm
+    pub max_iterations: usize, // Maximum number of iterations (inner iterations for GMRES)
+    pub restart: usize, // Krylov subspace size before GMRES restarts
+    pub initial_guess: Option<Vec<T>> // Starting point (zero vector if None)
+}
+
+// Default parameters for SolverOptions
+impl<T: Real> Default for SolverOptions<T> {
+    fn default() -> SolverOptions<T> {
+        SolverOptions {
+            tolerance: T::from_f64(1e-5),
+            max_iterations: 1000,
+            restart: 30,
+            initial_guess: None
+        }
+    }
+}
+
+/// Summary of an iterative solve
+#[derive(Debug, Clone)]
+pub struct ConvergenceReport<T = f32> {
+    pub converged: bool, // Whether the tolerance was reached
+    pub iterations: usize, // Number of iterations performed
+    pub residual_history: Vec<T> // Relative residual norm before the first and after every iteration
+}
+
+// Get dot product of two equal length slices (private function)
+fn dot<T: Real>(left: &[T], right: &[T]) -> T {
+    left.iter().zip(right).map(|(&l, &r)| l * r).sum()
+}
+
+// Get Euclidean norm of slice (private function)
+fn norm<T: Real>(vector: &[T]) -> T {
+    dot(vector, vector).sqrt()
+}
+
+// Compute y += alpha * x in place (private function)
+fn axpy<T: Real>(alpha: T, x: &[T], y: &mut [T]) {
+    for (y_value, &x_value) in y.iter_mut().zip(x) {
+        *y_value += alpha * x_value;
+    }
+}
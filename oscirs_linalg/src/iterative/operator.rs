@@ -0,0 +1,100 @@
+//! Linear operator abstraction used by the iterative solvers
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::sparse::csc::CscMatrix;
+use crate::sparse::csr::CsrMatrix;
+
+/// Trait for anything that can be multiplied with a vector, such as dense and sparse matrices
+pub trait LinearOperator<T> {
+    /// Get number of rows of the operator
+    fn get_rows(&self) -> usize;
+    /// Get number of columns of the operator
+    fn get_cols(&self) -> usize;
+    /// Multiply operator by vector
+    fn apply(&self, vector: &[T]) -> Result<Vec<T>>;
+    /// Get main diagonal of the operator
+    fn diagonal(&self) -> Vec<T>;
+}
+
+impl<T: Scalar> LinearOperator<T> for Matrix<T> {
+    fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    fn apply(&self, vector: &[T]) -> Result<Vec<T>> {
+        if vector.len() != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let output: Vec<T> = self.data
+            .chunks(self.cols.max(1))
+            .take(self.rows)
+            .map(|row| row.iter().zip(vector).map(|(&a, &x)| a * x).sum())
+            .collect();
+
+        Ok(output)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols))
+            .map(|idx| self.data[idx * self.cols + idx])
+            .collect()
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for CsrMatrix<T> {
+    fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    fn apply(&self, vector: &[T]) -> Result<Vec<T>> {
+        self.mul_vec(vector)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols))
+            .map(|idx| {
+                (self.row_ptr[idx]..self.row_ptr[idx + 1])
+                    .filter(|&entry| self.col_idx[entry] == idx)
+                    .map(|entry| self.values[entry])
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for CscMatrix<T> {
+    fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    fn apply(&self, vector: &[T]) -> Result<Vec<T>> {
+        self.mul_vec(vector)
+    }
+
+    fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols))
+            .map(|idx| {
+                (self.col_ptr[idx]..self.col_ptr[idx + 1])
+                    .filter(|&entry| self.row_idx[entry] == idx)
+                    .map(|entry| self.values[entry])
+                    .sum()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,171 @@
+//! Preconditioners for the iterative solvers
+
+use crate::Result;
+use crate::err::LAError;
+use crate::scalar::Real;
+use crate::sparse::csr::CsrMatrix;
+
+use super::operator::LinearOperator;
+
+/// Trait for approximate inverses M^-1 applied to residual vectors
+pub trait Preconditioner<T> {
+    /// Return M^-1 * vector
+    fn apply(&self, vector: &[T]) -> Vec<T>;
+}
+
+/// Preconditioner that leaves vectors unchanged
+pub struct Identity;
+
+impl<T: Real> Preconditioner<T> for Identity {
+    fn apply(&self, vector: &[T]) -> Vec<T> {
+        vector.to_vec()
+    }
+}
+
+/// Diagonal (Jacobi) preconditioner M = diag(A)
+pub struct Jacobi<T = f32> {
+    inv_diagonal: Vec<T> // Reciprocal of each diagonal entry
+}
+
+impl<T: Real> Jacobi<T> {
+    /// Build Jacobi preconditioner from the diagonal of a square operator
+    pub fn new(operator: &dyn LinearOperator<T>) -> Result<Jacobi<T>> {
+        if operator.get_rows() != operator.get_cols() {
+            return Err(LAError::SizeError)
+        }
+
+        let diagonal: Vec<T> = operator.diagonal();
+        if diagonal.iter().any(|&value| value == T::zero()) {
+            return Err(LAError::SingularMatrixError)
+        }
+
+        let inv_diagonal: Vec<T> = diagonal
+            .into_iter()
+            .map(|value| T::one() / value)
+            .collect();
+
+        Ok(Jacobi { inv_diagonal })
+    }
+}
+
+impl<T: Real> Preconditioner<T> for Jacobi<T> {
+    fn apply(&self, vector: &[T]) -> Vec<T> {
+        vector.iter()
+            .zip(&self.inv_diagonal)
+            .map(|(&value, &inv)| value * inv)
+            .collect()
+    }
+}
+
+/// Incomplete LU factorization with zero fill-in, ILU(0), restricted to the sparsity pattern of A
+pub struct Ilu0<T = f32> {
+    factors: CsrMatrix<T>, // Strictly lower part holds L (unit diagonal implied), rest holds U
+    diag_idx: Vec<usize> // Position of each diagonal entry in factors
+}
+
+impl<T: Real> Ilu0<T> {
+    /// Build ILU(0) preconditioner from a square CSR matrix with a full nonzero diagonal
+    pub fn new(matrix: &CsrMatrix<T>) -> Result<Ilu0<T>> {
+        let n: usize = matrix.rows;
+        if n != matrix.cols {
+            return Err(LAError::SizeError)
+        }
+
+        // Copy matrix with column indices sorted within every row
+        let mut col_idx: Vec<usize> = Vec::with_capacity(matrix.nnz());
+        let mut values: Vec<T> = Vec::with_capacity(matrix.nnz());
+        for row in 0..n {
+            let mut entries: Vec<(usize, T)> = (matrix.row_ptr[row]..matrix.row_ptr[row + 1])
+                .map(|entry| (matrix.col_idx[entry], matrix.values[entry]))
+                .collect();
+            entries.sort_by_key(|&(col, _)| col);
+
+            for (col, value) in entries {
+                col_idx.push(col);
+                values.push(value);
+            }
+        }
+        let row_ptr: Vec<usize> = matrix.row_ptr.clone();
+
+        // Locate diagonal entries
+        let mut diag_idx: Vec<usize> = Vec::with_capacity(n);
+        for row in 0..n {
+            let position: Option<usize> = (row_ptr[row]..row_ptr[row + 1])
+                .find(|&entry| col_idx[entry] == row);
+
+            match position {
+                Some(entry) => diag_idx.push(entry),
+                None => return Err(LAError::SingularMatrixError)
+            }
+        }
+
+        // Position of each column in the current row, if present in the pattern
+        let mut row_position: Vec<Option<usize>> = vec![None; n];
+
+        for row in 0..n {
+            for entry in row_ptr[row]..row_ptr[row + 1] {
+                row_position[col_idx[entry]] = Some(entry);
+            }
+
+            for entry in row_ptr[row]..diag_idx[row] {
+                let k: usize = col_idx[entry];
+                let pivot: T = values[diag_idx[k]];
+                if pivot == T::zero() {
+                    return Err(LAError::SingularMatrixError)
+                }
+
+                let factor: T = values[entry] / pivot;
+                values[entry] = factor;
+
+                // Eliminate using row k, dropping fill-in outside the pattern
+                for k_entry in (diag_idx[k] + 1)..row_ptr[k + 1] {
+                    if let Some(target) = row_position[col_idx[k_entry]] {
+                        let update: T = factor * values[k_entry];
+                        values[target] -= update;
+                    }
+                }
+            }
+
+            if values[diag_idx[row]] == T::zero() {
+                return Err(LAError::SingularMatrixError)
+            }
+
+            for entry in row_ptr[row]..row_ptr[row + 1] {
+                row_position[col_idx[entry]] = None;
+            }
+        }
+
+        let factors: CsrMatrix<T> = CsrMatrix { row_ptr, col_idx, values, rows: n, cols: n };
+
+        Ok(Ilu0 { factors, diag_idx })
+    }
+}
+
+impl<T: Real> Preconditioner<T> for Ilu0<T> {
+    fn apply(&self, vector: &[T]) -> Vec<T> {
+        let n: usize = self.factors.rows;
+        let row_ptr: &Vec<usize> = &self.factors.row_ptr;
+        let col_idx: &Vec<usize> = &self.factors.col_idx;
+        let values: &Vec<T> = &self.factors.values;
+
+        // Forward substitution with unit lower triangle
+        let mut output: Vec<T> = vector.to_vec();
+        for row in 0..n {
+            for entry in row_ptr[row]..self.diag_idx[row] {
+                let update: T = values[entry] * output[col_idx[entry]];
+                output[row] -= update;
+            }
+        }
+
+        // Back substitution with upper triangle
+        for row in (0..n).rev() {
+            for entry in (self.diag_idx[row] + 1)..row_ptr[row + 1] {
+                let update: T = values[entry] * output[col_idx[entry]];
+                output[row] -= update;
+            }
+            output[row] /= values[self.diag_idx[row]];
+        }
+
+        output
+    }
+}
@@ -0,0 +1,289 @@
+//! Safe declarative interface for custom OpenCL kernels
+//!
+//! A KernelBuilder declares the arguments a kernel takes after its output buffer, together with rules for the output
+//! shape and work sizes, so Calculator::run_kernel can check argument counts, types and shapes before enqueueing
+
+use crate::Result;
+use crate::err::LAError;
+use crate::calculator::MatrixHandle;
+
+/// Type of a declared kernel argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    Float, // const float
+    Int, // const int
+    Matrix // const global float* of a stored matrix
+}
+
+/// Value passed for a kernel argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KernelArg {
+    Float(f32), // Float scalar
+    Int(i32), // Int scalar
+    Matrix(MatrixHandle) // Matrix stored in Calculator memory
+}
+
+impl KernelArg {
+    /// Get type of argument
+    pub fn kind(&self) -> ArgKind {
+        match self {
+            KernelArg::Float(_) => ArgKind::Float,
+            KernelArg::Int(_) => ArgKind::Int,
+            KernelArg::Matrix(_) => ArgKind::Matrix
+        }
+    }
+}
+
+impl From<f32> for KernelArg {
+    fn from(value: f32) -> KernelArg {
+        KernelArg::Float(value)
+    }
+}
+
+impl From<i32> for KernelArg {
+    fn from(value: i32) -> KernelArg {
+        KernelArg::Int(value)
+    }
+}
+
+impl From<MatrixHandle> for KernelArg {
+    fn from(handle: MatrixHandle) -> KernelArg {
+        KernelArg::Matrix(handle)
+    }
+}
+
+/// Rule giving output shape from the input matrices (numbered in order among matrix arguments)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeRule {
+    Fixed(usize, usize), // Fixed rows x cols
+    SameAs(usize), // Same shape as input matrix
+    Transposed(usize), // Transposed shape of input matrix
+    Product(usize, usize) // Rows of first input matrix by columns of second input matrix
+}
+
+/// Rule giving global work sizes from the output shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkSizeRule {
+    Elements, // One work-item per output element
+    Grid, // rows x cols grid of work-items
+    Rows, // One work-item per output row
+    Cols, // One work-item per output column
+    Fixed(Vec<usize>) // Fixed work sizes (at most one work-item per output element)
+}
+
+/// Shape requirement between two input matrices (numbered in order among matrix arguments)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeCheck {
+    SameShape(usize, usize), // Both matrices have the same shape
+    Multipliable(usize, usize) // Columns of first matrix equal rows of second matrix
+}
+
+/// Builder declaring the signature of a custom kernel
+#[derive(Debug, Clone)]
+pub struct KernelBuilder {
+    pub(crate) program_source: String, // OpenCL C source
+    pub(crate) kernel_name: String, // Name of kernel in source
+    pub(crate) options: String, // OpenCL compiler options
+    args: Vec<ArgKind>, // Arguments after the output buffer, in order
+    shape_checks: Vec<ShapeCheck>, // Requirements on input matrix shapes
+    output_shape: ShapeRule, // Rule giving output shape
+    work_sizes: WorkSizeRule // Rule giving global work sizes
+}
+
+impl KernelBuilder {
+    /// Create builder for kernel with no arguments besides the output buffer (output shaped like first input, one work-item per element)
+    pub fn new(program_source: &str, kernel_name: &str) -> KernelBuilder {
+        KernelBuilder {
+            program_source: program_source.to_string(),
+            kernel_name: kernel_name.to_string(),
+            options: String::new(),
+            args: Vec::new(),
+            shape_checks: Vec::new(),
+            output_shape: ShapeRule::SameAs(0),
+            work_sizes: WorkSizeRule::Elements
+        }
+    }
+
+    /// Set OpenCL compiler options (e.g. "-cl-fast-relaxed-math")
+    pub fn options(mut self, options: &str) -> KernelBuilder {
+        self.options = options.to_string();
+        self
+    }
+
+    /// Declare next argument as const float
+    pub fn float_arg(mut self) -> KernelBuilder {
+        self.args.push(ArgKind::Float);
+        self
+    }
+
+    /// Declare next argument as const int
+    pub fn int_arg(mut self) -> KernelBuilder {
+        self.args.push(ArgKind::Int);
+        self
+    }
+
+    /// Declare next argument as input matrix
+    pub fn matrix_arg(mut self) -> KernelBuilder {
+        self.args.push(ArgKind::Matrix);
+        self
+    }
+
+    /// Require shape relation between input matrices
+    pub fn check_shapes(mut self, shape_check: ShapeCheck) -> KernelBuilder {
+        self.shape_checks.push(shape_check);
+        self
+    }
+
+    /// Set rule giving output shape
+    pub fn output_shape(mut self, output_shape: ShapeRule) -> KernelBuilder {
+        self.output_shape = output_shape;
+        self
+    }
+
+    /// Set rule giving global work sizes
+    pub fn work_sizes(mut self, work_sizes: WorkSizeRule) -> KernelBuilder {
+        self.work_sizes = work_sizes;
+        self
+    }
+
+    /// Get declared argument types in order
+    pub fn get_args(&self) -> &[ArgKind] {
+        &self.args
+    }
+
+    /// Check that every rule refers to a declared input matrix
+    pub fn validate(&self) -> Result<()> {
+        let n_matrices: usize = self.args.iter().filter(|kind| **kind == ArgKind::Matrix).count();
+
+        let mut referenced: Vec<usize> = match self.output_shape {
+            ShapeRule::Fixed(..) => Vec::new(),
+            ShapeRule::SameAs(idx) | ShapeRule::Transposed(idx) => vec![idx],
+            ShapeRule::Product(left, right) => vec![left, right]
+        };
+        for shape_check in self.shape_checks.iter() {
+            match *shape_check {
+                ShapeCheck::SameShape(left, right) | ShapeCheck::Multipliable(left, right) => referenced.extend([left, right])
+            }
+        }
+
+        if referenced.iter().any(|idx| *idx >= n_matrices) {
+            return Err(LAError::IndexError)
+        }
+
+        Ok(())
+    }
+
+    /// Check declared signature against the argument type names of the compiled kernel (None where not reported)
+    pub fn check_kernel_args(&self, arg_types: &[Option<String>]) -> Result<()> {
+        // Output buffer comes before the declared arguments
+        if arg_types.len() != 1 + self.args.len() {
+            return Err(LAError::ArgumentError)
+        }
+
+        let expected: Vec<&str> = std::iter::once("float*")
+            .chain(self.args.iter().map(|kind| match kind {
+                ArgKind::Float => "float",
+                ArgKind::Int => "int",
+                ArgKind::Matrix => "float*"
+            }))
+            .collect();
+
+        let mismatched: bool = arg_types
+            .iter()
+            .zip(expected)
+            .any(|(arg_type, expected_type)| arg_type.as_ref().is_some_and(|type_name| {
+                type_name.split_whitespace().collect::<String>() != expected_type
+            }));
+
+        if mismatched {
+            return Err(LAError::ArgumentError)
+        }
+
+        Ok(())
+    }
+
+    /// Check args against the declared signature and return output rows, output cols and work sizes
+    pub fn launch_params(&self, args: &[KernelArg]) -> Result<(usize, usize, Vec<usize>)> {
+        self.validate()?;
+
+        if args.len() != self.args.len() {
+            return Err(LAError::ArgumentError)
+        }
+
+        if args.iter().zip(self.args.iter()).any(|(arg, kind)| arg.kind() != *kind) {
+            return Err(LAError::ArgumentError)
+        }
+
+        // Shapes of input matrices in argument order
+        let shapes: Vec<(usize, usize)> = args
+            .iter()
+            .filter_map(|arg| match arg {
+                KernelArg::Matrix(handle) => Some((handle.get_rows(), handle.get_cols())),
+                _ => None
+            })
+            .collect();
+
+        for shape_check in self.shape_checks.iter() {
+            let valid: bool = match *shape_check {
+                ShapeCheck::SameShape(left, right) => shapes[left] == shapes[right],
+                ShapeCheck::Multipliable(left, right) => shapes[left].1 == shapes[right].0
+            };
+
+            if !valid {
+                return Err(LAError::SizeError)
+            }
+        }
+
+        let (output_rows, output_cols) = match self.output_shape {
+            ShapeRule::Fixed(rows, cols) => (rows, cols),
+            ShapeRule::SameAs(idx) => shapes[idx],
+            ShapeRule::Transposed(idx) => (shapes[idx].1, shapes[idx].0),
+            ShapeRule::Product(left, right) => {
+                if shapes[left].1 != shapes[right].0 {
+                    return Err(LAError::SizeError)
+                }
+
+                (shapes[left].0, shapes[right].1)
+            }
+        };
+
+        let work_sizes: Vec<usize> = match &self.work_sizes {
+            WorkSizeRule::Elements => vec![output_rows * output_cols],
+            WorkSizeRule::Grid => vec![output_rows, output_cols],
+            WorkSizeRule::Rows => vec![output_rows],
+            WorkSizeRule::Cols => vec![output_cols],
+            WorkSizeRule::Fixed(work_sizes) => {
+                // More work-items than output elements could write past the output buffer
+                let total: Option<usize> = work_sizes.iter().try_fold(1_usize, |total, size| total.checked_mul(*size));
+
+                if total.is_none_or(|total| total > output_rows * output_cols) {
+                    return Err(LAError::SizeError)
+                }
+
+                work_sizes.clone()
+            }
+        };
+
+        // Empty buffers and work sizes cannot be enqueued
+        if output_rows * output_cols == 0 || work_sizes.is_empty() || work_sizes.contains(&0) {
+            return Err(LAError::SizeError)
+        }
+
+        Ok((output_rows, output_cols, work_sizes))
+    }
+}
+
+/// Custom kernel compiled by Calculator::load_kernel
+#[derive(Debug, Clone)]
+pub struct CustomKernel {
+    pub(crate) calculator_id: usize, // Id of Calculator that compiled the kernel
+    pub(crate) kernel_index: usize, // Kernel index in backend
+    pub(crate) signature: KernelBuilder // Declared signature
+}
+
+impl CustomKernel {
+    /// Get declared signature
+    pub fn get_signature(&self) -> &KernelBuilder {
+        &self.signature
+    }
+}
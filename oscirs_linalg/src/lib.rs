@@ -8,7 +8,19 @@ use err::LAError;
 
 pub mod err;
 pub mod calculator;
+pub mod expr;
+pub mod kernel;
 pub mod matrix;
+pub mod view;
+pub mod elementwise;
+pub mod scalar;
+pub mod complex;
+pub mod decomp;
+pub mod sparse;
+pub mod iterative;
+mod norm;
+mod matmul;
+mod backend;
 mod memory;
 
 /// Custom result type
@@ -17,9 +29,21 @@ pub type Result<T> = result::Result<T, LAError>;
 /// Default amount of memory slots for matrices in MemoryHandler and Calculator
 const INIT_MEMORY_CAPACITY: usize = 3;
 
-/// List of default kernel names
-const PROGRAM_LIST: [&str; 1] = [
-    "mat_mul"
+/// List of default kernel names (mat_mul must stay first)
+const PROGRAM_LIST: [&str; 13] = [
+    "mat_mul",
+    "mat_add",
+    "mat_sub",
+    "mat_hadamard",
+    "scalar_add",
+    "scalar_mul",
+    "transpose",
+    "sum_rows",
+    "sum_cols",
+    "unary_exp",
+    "unary_log",
+    "unary_sqrt",
+    "unary_tanh"
 ];
 
 /// Source code for default kernels
@@ -41,4 +65,188 @@ kernel void mat_mul (
 
     c[globalRow * N + globalCol] = interm;
 }
-"#;
\ No newline at end of file
+
+kernel void mat_add (
+    global float* c,
+    const global float* a,
+    const global float* b
+) {
+    const int i = get_global_id(0);
+
+    c[i] = a[i] + b[i];
+}
+
+kernel void mat_sub (
+    global float* c,
+    const global float* a,
+    const global float* b
+) {
+    const int i = get_global_id(0);
+
+    c[i] = a[i] - b[i];
+}
+
+kernel void mat_hadamard (
+    global float* c,
+    const global float* a,
+    const global float* b
+) {
+    const int i = get_global_id(0);
+
+    c[i] = a[i] * b[i];
+}
+
+kernel void scalar_add (
+    global float* c,
+    const float s,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = a[i] + s;
+}
+
+kernel void scalar_mul (
+    global float* c,
+    const float s,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = a[i] * s;
+}
+
+kernel void transpose (
+    global float* c,
+    const int R,
+    const int C,
+    const global float* a
+) {
+    const int globalRow = get_global_id(0);
+    const int globalCol = get_global_id(1);
+
+    c[globalCol * R + globalRow] = a[globalRow * C + globalCol];
+}
+
+kernel void sum_rows (
+    global float* c,
+    const int C,
+    const global float* a
+) {
+    const int globalRow = get_global_id(0);
+
+    float total = 0.0f;
+    for (int col = 0; col < C; col++) {
+        total += a[globalRow * C + col];
+    }
+
+    c[globalRow] = total;
+}
+
+kernel void sum_cols (
+    global float* c,
+    const int R,
+    const int C,
+    const global float* a
+) {
+    const int globalCol = get_global_id(0);
+
+    float total = 0.0f;
+    for (int row = 0; row < R; row++) {
+        total += a[row * C + globalCol];
+    }
+
+    c[globalCol] = total;
+}
+
+kernel void unary_exp (
+    global float* c,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = exp(a[i]);
+}
+
+kernel void unary_log (
+    global float* c,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = log(a[i]);
+}
+
+kernel void unary_sqrt (
+    global float* c,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = sqrt(a[i]);
+}
+
+kernel void unary_tanh (
+    global float* c,
+    const global float* a
+) {
+    const int i = get_global_id(0);
+
+    c[i] = tanh(a[i]);
+}
+"#;
+
+/// Candidate edge lengths for square tiles of the tiled mat_mul kernel, largest first (multiples of 4)
+const MAT_MUL_TILE_SIZES: [usize; 3] = [32, 16, 8];
+
+/// Name of tiled mat_mul kernel
+const TILED_MAT_MUL_NAME: &str = "mat_mul_tiled";
+
+/// Source code for tiled mat_mul kernel (compiled with -D TILE_SIZE=n, falls back to mat_mul if unusable)
+const TILED_MAT_MUL_SOURCE: &str = r#"
+#ifndef TILE_SIZE
+#define TILE_SIZE 16
+#endif
+
+kernel void mat_mul_tiled (
+    global float* c,
+    const int M,
+    const int N,
+    const int K,
+    const global float* a,
+    const global float* b
+) {
+    const int localRow = get_local_id(0);
+    const int localCol = get_local_id(1);
+    const int globalRow = get_group_id(0) * TILE_SIZE + localRow;
+    const int globalCol = get_group_id(1) * TILE_SIZE + localCol;
+
+    // b tile is stored transposed so both tiles are contiguous along k for vector loads
+    local float aTile[TILE_SIZE][TILE_SIZE];
+    local float bTileT[TILE_SIZE][TILE_SIZE];
+
+    float interm = 0.0f;
+    const int nTiles = (K + TILE_SIZE - 1) / TILE_SIZE;
+
+    for (int t = 0; t < nTiles; t++) {
+        const int aCol = t * TILE_SIZE + localCol;
+        const int bRow = t * TILE_SIZE + localRow;
+
+        // Zero padding past the matrix edges keeps partial tiles correct
+        aTile[localRow][localCol] = (globalRow < M && aCol < K) ? a[globalRow * K + aCol] : 0.0f;
+        bTileT[localCol][localRow] = (bRow < K && globalCol < N) ? b[bRow * N + globalCol] : 0.0f;
+
+        barrier(CLK_LOCAL_MEM_FENCE);
+
+        for (int k = 0; k < TILE_SIZE; k += 4) {
+            interm += dot(vload4(0, &aTile[localRow][k]), vload4(0, &bTileT[localCol][k]));
+        }
+
+        barrier(CLK_LOCAL_MEM_FENCE);
+    }
+
+    if (globalRow < M && globalCol < N) {
+        c[globalRow * N + globalCol] = interm;
+    }
+}
+"#;
@@ -0,0 +1,96 @@
+//! Cache-blocked CPU matrix multiplication, multithreaded with the "parallel" feature
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+/// Number of inner dimension entries in each packed rhs block
+const BLOCK_INNER: usize = 128;
+
+/// Number of rhs columns in each packed rhs block
+const BLOCK_COLS: usize = 256;
+
+/// Number of multiply-adds below which products stay on the calling thread
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 18;
+
+/// Multiply lhs by rhs using cache blocking (and multiple threads with the "parallel" feature)
+pub(crate) fn mat_mul<T: Scalar>(lhs: &Matrix<T>, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+    if lhs.cols != rhs.rows {
+        return Err(LAError::SizeError)
+    }
+
+    let mut output_data: Vec<T> = vec![T::zero(); lhs.rows * rhs.cols];
+
+    // Empty inner or output dimensions leave the product all zeros
+    if lhs.cols > 0 && rhs.cols > 0 {
+        mul_all_rows(lhs, rhs, &mut output_data);
+    }
+
+    Ok(Matrix { data: output_data, rows: lhs.rows, cols: rhs.cols })
+}
+
+// Compute every output row on the calling thread (private function)
+#[cfg(not(feature = "parallel"))]
+fn mul_all_rows<T: Scalar>(lhs: &Matrix<T>, rhs: &Matrix<T>, output_data: &mut [T]) {
+    mul_rows(&lhs.data, lhs.cols, rhs, output_data);
+}
+
+// Split output rows into contiguous bands computed on scoped threads (private function)
+#[cfg(feature = "parallel")]
+fn mul_all_rows<T: Scalar>(lhs: &Matrix<T>, rhs: &Matrix<T>, output_data: &mut [T]) {
+    let n_threads: usize = std::thread::available_parallelism()
+        .map_or(1, |count| count.get())
+        .min(lhs.rows);
+
+    if n_threads <= 1 || lhs.rows * lhs.cols * rhs.cols < PARALLEL_THRESHOLD {
+        mul_rows(&lhs.data, lhs.cols, rhs, output_data);
+        return;
+    }
+
+    let rows_per_thread: usize = lhs.rows.div_ceil(n_threads);
+
+    std::thread::scope(|scope| {
+        let lhs_bands = lhs.data.chunks(rows_per_thread * lhs.cols);
+        let output_bands = output_data.chunks_mut(rows_per_thread * rhs.cols);
+
+        for (lhs_band, output_band) in lhs_bands.zip(output_bands) {
+            scope.spawn(move || mul_rows(lhs_band, lhs.cols, rhs, output_band));
+        }
+    });
+}
+
+// Accumulate product of row-major lhs rows and rhs into matching output rows (private function)
+fn mul_rows<T: Scalar>(lhs_rows: &[T], inner: usize, rhs: &Matrix<T>, output_rows: &mut [T]) {
+    let n_cols: usize = rhs.cols;
+    let mut packed: Vec<T> = Vec::with_capacity(BLOCK_INNER.min(inner) * BLOCK_COLS.min(n_cols));
+
+    for inner_start in (0..inner).step_by(BLOCK_INNER) {
+        let inner_end: usize = (inner_start + BLOCK_INNER).min(inner);
+
+        for col_start in (0..n_cols).step_by(BLOCK_COLS) {
+            let col_end: usize = (col_start + BLOCK_COLS).min(n_cols);
+            let width: usize = col_end - col_start;
+
+            // Pack rhs block contiguously so it stays cache resident across all lhs rows
+            packed.clear();
+            for k in inner_start..inner_end {
+                packed.extend_from_slice(&rhs.data[k * n_cols + col_start..k * n_cols + col_end]);
+            }
+
+            for (lhs_row, output_row) in lhs_rows.chunks_exact(inner).zip(output_rows.chunks_exact_mut(n_cols)) {
+                let output_block: &mut [T] = &mut output_row[col_start..col_end];
+
+                // Contiguous axpy over the block row lets the compiler vectorize the inner loop
+                for (k, packed_row) in (inner_start..inner_end).zip(packed.chunks_exact(width)) {
+                    let lhs_value: T = lhs_row[k];
+
+                    for (output_value, &rhs_value) in output_block.iter_mut().zip(packed_row) {
+                        *output_value += lhs_value * rhs_value;
+                    }
+                }
+            }
+        }
+    }
+}
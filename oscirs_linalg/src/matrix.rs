@@ -4,24 +4,27 @@ use std::ops;
 
 use crate::Result;
 use crate::err::LAError;
+use crate::complex::Complex;
+use crate::matmul;
+use crate::scalar::Scalar;
 
-/// Matrix object definition
+/// Matrix object definition, generic over its element type (defaults to f32)
 #[derive(Debug, Clone)]
-pub struct Matrix {
-    data: Vec<f32>, // Data vector
-    rows: usize, // Number of rows
-    cols: usize // Number of columns
+pub struct Matrix<T = f32> {
+    pub(crate) data: Vec<T>, // Data vector
+    pub(crate) rows: usize, // Number of rows
+    pub(crate) cols: usize // Number of columns
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
     /// Create new matrix (includes checking for matching number of elements)
-    pub fn new(input_data: Vec<f32>, rows: usize, cols: usize) -> Result<Matrix> {
+    pub fn new(input_data: Vec<T>, rows: usize, cols: usize) -> Result<Matrix<T>> {
         let vec_len: usize = input_data.len(); // Get input_data length
         let comp_len: usize = rows * cols; // Get projected matrix size
         
         if vec_len == comp_len { // If provided row and col values are reasonable
             // Create and return new matrix struct
-            let output: Matrix = Matrix { data: input_data, rows: rows, cols: cols };
+            let output: Matrix<T> = Matrix { data: input_data, rows: rows, cols: cols };
             Ok(output)
         }
         else { // If provided row and col values are not reasonable
@@ -30,6 +33,120 @@ impl Matrix {
         }
     }
 
+    /// Create rows x cols matrix filled with zeros
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix { data: vec![T::zero(); rows * cols], rows, cols }
+    }
+
+    /// Create rows x cols matrix filled with ones
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix { data: vec![T::one(); rows * cols], rows, cols }
+    }
+
+    /// Create n x n identity matrix
+    pub fn identity(n: usize) -> Matrix<T> {
+        Matrix::from_fn(n, n, |row, col| if row == col { T::one() } else { T::zero() })
+    }
+
+    /// Create square matrix with values along its diagonal
+    pub fn diag(values: &[T]) -> Matrix<T> {
+        let n: usize = values.len();
+
+        Matrix::from_fn(n, n, |row, col| if row == col { values[row] } else { T::zero() })
+    }
+
+    /// Create rows x cols matrix whose value at row, col index is element_fn(row, col)
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut element_fn: F) -> Matrix<T> {
+        let mut output_data: Vec<T> = Vec::with_capacity(rows * cols);
+
+        for row in 0..rows {
+            for col in 0..cols {
+                output_data.push(element_fn(row, col));
+            }
+        }
+
+        Matrix { data: output_data, rows, cols }
+    }
+
+    /// Create matrix from a list of rows (all rows must have the same length)
+    pub fn from_rows<R: AsRef<[T]>>(input_rows: &[R]) -> Result<Matrix<T>> {
+        let rows: usize = input_rows.len();
+        let cols: usize = input_rows.first().map_or(0, |row| row.as_ref().len());
+        let mut output_data: Vec<T> = Vec::with_capacity(rows * cols);
+
+        for row in input_rows {
+            if row.as_ref().len() != cols {
+                return Err(LAError::SizeError)
+            }
+
+            output_data.extend_from_slice(row.as_ref());
+        }
+
+        Ok(Matrix { data: output_data, rows, cols })
+    }
+
+    /// Create matrix from a list of columns (all columns must have the same length)
+    pub fn from_cols<C: AsRef<[T]>>(input_cols: &[C]) -> Result<Matrix<T>> {
+        Ok(Matrix::from_rows(input_cols)?.transpose())
+    }
+
+    /// Concatenate matrices side by side (all must have the same number of rows)
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>> {
+        let rows: usize = matrices.first().map_or(0, |matrix| matrix.rows);
+        if matrices.iter().any(|matrix| matrix.rows != rows) {
+            return Err(LAError::SizeError)
+        }
+
+        let cols: usize = matrices.iter().map(|matrix| matrix.cols).sum();
+        let mut output_data: Vec<T> = Vec::with_capacity(rows * cols);
+
+        for row in 0..rows {
+            for matrix in matrices {
+                output_data.extend_from_slice(&matrix.data[row * matrix.cols..(row + 1) * matrix.cols]);
+            }
+        }
+
+        Ok(Matrix { data: output_data, rows, cols })
+    }
+
+    /// Concatenate matrices top to bottom (all must have the same number of columns)
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>> {
+        let cols: usize = matrices.first().map_or(0, |matrix| matrix.cols);
+        if matrices.iter().any(|matrix| matrix.cols != cols) {
+            return Err(LAError::SizeError)
+        }
+
+        let rows: usize = matrices.iter().map(|matrix| matrix.rows).sum();
+        let mut output_data: Vec<T> = Vec::with_capacity(rows * cols);
+
+        for matrix in matrices {
+            output_data.extend_from_slice(&matrix.data);
+        }
+
+        Ok(Matrix { data: output_data, rows, cols })
+    }
+
+    /// Assemble block matrix from rows of blocks (blocks in a row share a row count, block rows share a total column count)
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>> {
+        let block_rows: Vec<Matrix<T>> = blocks.iter()
+            .map(|block_row| Matrix::hstack(block_row))
+            .collect::<Result<Vec<Matrix<T>>>>()?;
+        let block_row_refs: Vec<&Matrix<T>> = block_rows.iter().collect();
+
+        Matrix::vstack(&block_row_refs)
+    }
+
+    /// Return Kronecker product of self and rhs
+    pub fn kron(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let rows: usize = self.rows * rhs.rows;
+        let cols: usize = self.cols * rhs.cols;
+
+        Matrix::from_fn(rows, cols, |row, col| {
+            self.data[(row / rhs.rows) * self.cols + col / rhs.cols]
+                * rhs.data[(row % rhs.rows) * rhs.cols + col % rhs.cols]
+        })
+    }
+
     /// Get number of rows in matrix
     pub fn get_rows(&self) -> usize {
         return self.rows
@@ -53,12 +170,12 @@ impl Matrix {
     }
 
     /// Get data of matrix in row-major vector form
-    pub fn get_data(&self) -> Vec<f32> {
+    pub fn get_data(&self) -> Vec<T> {
         return self.data.clone()
     }
 
     /// Update matrix data (must have same total number of elements)
-    pub fn update_data(&mut self, new_data: Vec<f32>) -> Result<()> {
+    pub fn update_data(&mut self, new_data: Vec<T>) -> Result<()> {
         if (self.rows * self.cols) != new_data.len() {
             return Err(LAError::DataUpdateError)
         }
@@ -68,8 +185,26 @@ impl Matrix {
         Ok(())
     }
 
+    /// Get value at row, col index
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Result<T> {
+        if row_idx >= self.rows || col_idx >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        Ok(self.data[row_idx * self.cols + col_idx])
+    }
+
+    /// Get mutable reference to value at row, col index
+    pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Result<&mut T> {
+        if row_idx >= self.rows || col_idx >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        Ok(&mut self.data[row_idx * self.cols + col_idx])
+    }
+
     /// Return selected row of self
-    pub fn row(&self, row_idx: usize) -> Result<Vec<f32>> {
+    pub fn row(&self, row_idx: usize) -> Result<Vec<T>> {
         if row_idx >= self.rows {
             return Err(LAError::IndexError)
         }
@@ -77,7 +212,7 @@ impl Matrix {
         let lower_data_idx: usize = row_idx * self.cols;
         let upper_data_idx: usize = (row_idx + 1) * self.cols;
 
-        let output_row: Vec<f32> = (lower_data_idx..upper_data_idx)
+        let output_row: Vec<T> = (lower_data_idx..upper_data_idx)
             .map(|idx| self.data[idx])
             .collect();
 
@@ -85,12 +220,12 @@ impl Matrix {
     }
 
     /// Return selected col of self
-    pub fn col(&self, col_idx: usize) -> Result<Vec<f32>> {
+    pub fn col(&self, col_idx: usize) -> Result<Vec<T>> {
         if col_idx >= self.cols {
             return Err(LAError::IndexError)
         }
 
-        let output_col: Vec<f32> = (0..self.rows)
+        let output_col: Vec<T> = (0..self.rows)
             .map(|row| self.data[row * self.cols + col_idx])
             .collect();
 
@@ -98,8 +233,8 @@ impl Matrix {
     }
 
     /// Return transpose of self
-    pub fn transpose(&self) -> Matrix {
-        let mut transpose_data: Vec<f32> = Vec::with_capacity(self.rows * self.cols); // Initialize vector for transpose data
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transpose_data: Vec<T> = Vec::with_capacity(self.rows * self.cols); // Initialize vector for transpose data
 
         for transpose_row in 0..self.cols { // Loop through original cols (transpose rows)
             for transpose_col in 0..self.rows { // Loop through original rows (transpose cols)
@@ -109,144 +244,408 @@ impl Matrix {
 
         Matrix { data: transpose_data, rows: self.cols, cols: self.rows } // Create and return transpose matrix
     }
+
+    /// Return conjugate transpose of self (same as transpose for real element types)
+    pub fn adjoint(&self) -> Matrix<T> {
+        let mut adjoint: Matrix<T> = self.transpose();
+
+        for item in &mut adjoint.data {
+            *item = item.conj();
+        }
+
+        adjoint
+    }
+
+    /// Check whether self is equal to its conjugate transpose
+    pub fn is_hermitian(&self) -> bool {
+        if self.rows != self.cols {
+            return false
+        }
+
+        (0..self.rows).all(|row| {
+            (row..self.cols).all(|col| self[[row, col]] == self[[col, row]].conj())
+        })
+    }
+
+    // Combine each element of self in place with the matching element of rhs (private function)
+    fn zip_in_place<F: Fn(&mut T, T)>(&mut self, rhs: &Matrix<T>, combine: F) -> Result<()> {
+        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
+            return Err(LAError::SizeError)
+        }
+
+        for (item, &rhs_item) in self.data.iter_mut().zip(&rhs.data) {
+            combine(item, rhs_item);
+        }
+
+        Ok(())
+    }
+
+    // Multiply self by rhs into a new matrix (private function)
+    fn mat_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        matmul::mat_mul(self, rhs)
+    }
 }
 
-// Get matrix value at row, col index
-impl ops::Index<[usize; 2]> for Matrix {
-    type Output = f32;
+// Get matrix value at row, col index (panics if out of bounds)
+impl<T: Scalar> ops::Index<[usize; 2]> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        if idx[0] >= self.rows || idx[1] >= self.cols {
+            panic!("Matrix index {:?} out of bounds for {}x{} matrix", idx, self.rows, self.cols);
+        }
 
-    fn index(&self, idx: [usize; 2]) -> &f32 {
         let linear_index: usize = self.cols * idx[0] + idx[1];
         
         &self.data[linear_index]
     }
 }
 
-// Add f32 to Matrix
-impl ops::Add<f32> for Matrix {
-    type Output = Matrix;
-
-    fn add(self, rhs: f32) -> Matrix {
-        let mut output_data: Vec<f32> = self.data.clone();
-        
-        for item in &mut output_data {
-            *item += rhs;
+// Get mutable matrix value at row, col index (panics if out of bounds)
+impl<T: Scalar> ops::IndexMut<[usize; 2]> for Matrix<T> {
+    fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
+        if idx[0] >= self.rows || idx[1] >= self.cols {
+            panic!("Matrix index {:?} out of bounds for {}x{} matrix", idx, self.rows, self.cols);
         }
 
-        Matrix { data: output_data, rows: self.rows, cols: self.cols }
+        let linear_index: usize = self.cols * idx[0] + idx[1];
+
+        &mut self.data[linear_index]
+    }
+}
+
+// Add scalar to Matrix (reuses data allocation)
+impl<T: Scalar> ops::Add<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, rhs: T) -> Matrix<T> {
+        self += rhs;
+        self
+    }
+}
+
+// Add scalar to borrowed Matrix
+impl<T: Scalar> ops::Add<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: T) -> Matrix<T> {
+        self.clone() + rhs
     }
 }
 
 // Add Matrix to Matrix
-impl ops::Add<Matrix> for Matrix {
-    type Output = Result<Matrix>;
+impl<T: Scalar> ops::Add<Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn add(self, rhs: Matrix) -> Result<Matrix> {
-        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
-            return Err(LAError::SizeError)
-        }
+    fn add(self, rhs: Matrix<T>) -> Result<Matrix<T>> {
+        self + &rhs
+    }
+}
 
-        let n_elements: usize = self.rows * self.cols;
-        let mut output_data: Vec<f32> = Vec::with_capacity(n_elements);
+// Add borrowed Matrix to Matrix (reuses left hand side allocation)
+impl<T: Scalar> ops::Add<&Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-        for element_index in 0..n_elements {
-            output_data.push(self.data[element_index] + rhs.data[element_index]);
-        }
+    fn add(mut self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.zip_in_place(rhs, |item, rhs_item| *item += rhs_item)?;
 
-        Ok(Matrix { data: output_data, rows: self.rows, cols: self.cols })
+        Ok(self)
     }
 }
 
-// Add Matrix to f32
-impl ops::Add<Matrix> for f32 {
-    type Output = Matrix;
+// Add Matrix to borrowed Matrix (reuses right hand side allocation)
+impl<T: Scalar> ops::Add<Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn add(self, rhs: Matrix) -> Matrix {
-        rhs + self
+    fn add(self, mut rhs: Matrix<T>) -> Result<Matrix<T>> {
+        rhs.zip_in_place(self, |item, lhs_item| *item = lhs_item + *item)?;
+
+        Ok(rhs)
     }
 }
 
-// Negate Matrix
-impl ops::Neg for Matrix {
-    type Output = Matrix;
+// Add borrowed Matrix to borrowed Matrix
+impl<T: Scalar> ops::Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn neg(self) -> Matrix {
-        let n_elements: usize = self.rows * self.cols;
-        let mut output_data: Vec<f32> = Vec::with_capacity(n_elements);
-        
-        for element_index in 0..n_elements {
-            output_data.push(-self.data[element_index]);
+    fn add(self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.clone() + rhs
+    }
+}
+
+// Negate Matrix (reuses data allocation)
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        for item in &mut self.data {
+            *item = -*item;
         }
 
-        Matrix { data: output_data, rows: self.rows, cols: self.cols }
+        self
     }
 }
 
-// Subtract f32 from Matrix
-impl ops::Sub<f32> for Matrix {
-    type Output = Matrix;
+// Negate borrowed Matrix
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for &Matrix<T> {
+    type Output = Matrix<T>;
 
-    fn sub(self, rhs: f32) -> Matrix {
-        self + -rhs
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+// Subtract scalar from Matrix (reuses data allocation)
+impl<T: Scalar> ops::Sub<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: T) -> Matrix<T> {
+        self -= rhs;
+        self
+    }
+}
+
+// Subtract scalar from borrowed Matrix
+impl<T: Scalar> ops::Sub<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: T) -> Matrix<T> {
+        self.clone() - rhs
     }
 }
 
 // Subtract Matrix from Matrix
-impl ops::Sub<Matrix> for Matrix {
-    type Output = Result<Matrix>;
+impl<T: Scalar> ops::Sub<Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn sub(self, rhs: Matrix) -> Result<Matrix> {
-        self + -rhs
+    fn sub(self, rhs: Matrix<T>) -> Result<Matrix<T>> {
+        self - &rhs
     }
 }
 
-// Subtract Matrix from f32
-impl ops::Sub<Matrix> for f32 {
-    type Output = Matrix;
+// Subtract borrowed Matrix from Matrix (reuses left hand side allocation)
+impl<T: Scalar> ops::Sub<&Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
+
+    fn sub(mut self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.zip_in_place(rhs, |item, rhs_item| *item -= rhs_item)?;
 
-    fn sub(self, rhs: Matrix) -> Matrix {
-        self + -rhs
+        Ok(self)
     }
 }
 
-// Multiply f32 by Matrix
-impl ops::Mul<Matrix> for f32 {
-    type Output = Matrix;
+// Subtract Matrix from borrowed Matrix (reuses right hand side allocation)
+impl<T: Scalar> ops::Sub<Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn mul(self, rhs: Matrix) -> Matrix {
-        let mut output_data: Vec<f32> = rhs.data.clone();
-        
-        for item in &mut output_data {
-            *item *= self;
-        }
+    fn sub(self, mut rhs: Matrix<T>) -> Result<Matrix<T>> {
+        rhs.zip_in_place(self, |item, lhs_item| *item = lhs_item - *item)?;
+
+        Ok(rhs)
+    }
+}
+
+// Subtract borrowed Matrix from borrowed Matrix
+impl<T: Scalar> ops::Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.clone() - rhs
+    }
+}
+
+// Multiply Matrix by scalar (reuses data allocation)
+impl<T: Scalar> ops::Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
 
-        Matrix { data: output_data, rows: rhs.rows, cols: rhs.cols }
+    fn mul(mut self, rhs: T) -> Matrix<T> {
+        self *= rhs;
+        self
+    }
+}
+
+// Multiply borrowed Matrix by scalar
+impl<T: Scalar> ops::Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Matrix<T> {
+        self.clone() * rhs
     }
 }
 
 // Multiply Matrix by Matrix
-impl ops::Mul<Matrix> for Matrix {
-    type Output = Result<Matrix>;
+impl<T: Scalar> ops::Mul<Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
 
-    fn mul(self, rhs: Matrix) -> Result<Matrix> {
-        if self.cols != rhs.rows {
-            return Err(LAError::SizeError)
+    fn mul(self, rhs: Matrix<T>) -> Result<Matrix<T>> {
+        self.mat_mul(&rhs)
+    }
+}
+
+// Multiply Matrix by borrowed Matrix
+impl<T: Scalar> ops::Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Result<Matrix<T>>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.mat_mul(rhs)
+    }
+}
+
+// Multiply borrowed Matrix by Matrix
+impl<T: Scalar> ops::Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
+
+    fn mul(self, rhs: Matrix<T>) -> Result<Matrix<T>> {
+        self.mat_mul(&rhs)
+    }
+}
+
+// Multiply borrowed Matrix by borrowed Matrix
+impl<T: Scalar> ops::Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.mat_mul(rhs)
+    }
+}
+
+// Add scalar to Matrix in place
+impl<T: Scalar> ops::AddAssign<T> for Matrix<T> {
+    fn add_assign(&mut self, rhs: T) {
+        for item in &mut self.data {
+            *item += rhs;
         }
-        let new_n_elements: usize = self.rows * rhs.cols;
-        let mut output_data: Vec<f32> = Vec::with_capacity(new_n_elements);
+    }
+}
 
-        for lhs_row in 0..self.rows {
-            for rhs_col in 0..rhs.cols {
-                let mut dot_prod: f32 = 0.0;
+// Add Matrix to Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::AddAssign<Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+// Add borrowed Matrix to Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        self.zip_in_place(rhs, |item, rhs_item| *item += rhs_item)
+            .expect("Matrix dimensions do not match for addition");
+    }
+}
+
+// Subtract scalar from Matrix in place
+impl<T: Scalar> ops::SubAssign<T> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        for item in &mut self.data {
+            *item -= rhs;
+        }
+    }
+}
+
+// Subtract Matrix from Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::SubAssign<Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+// Subtract borrowed Matrix from Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        self.zip_in_place(rhs, |item, rhs_item| *item -= rhs_item)
+            .expect("Matrix dimensions do not match for subtraction");
+    }
+}
+
+// Multiply Matrix by scalar in place
+impl<T: Scalar> ops::MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        for item in &mut self.data {
+            *item *= rhs;
+        }
+    }
+}
+
+// Multiply Matrix by Matrix in place (panics if inner dimensions do not match)
+impl<T: Scalar> ops::MulAssign<Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self *= &rhs;
+    }
+}
+
+// Multiply Matrix by borrowed Matrix in place (panics if inner dimensions do not match)
+impl<T: Scalar> ops::MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = self.mat_mul(rhs)
+            .expect("Matrix dimensions do not match for multiplication");
+    }
+}
 
-                for dot_index in 0..self.cols {
-                    dot_prod += self[[lhs_row, dot_index]] * rhs[[dot_index, rhs_col]];
+// Implement operators with a primitive scalar on the left hand side (generic impls are not allowed here)
+macro_rules! impl_scalar_lhs_ops {
+    ($($t:ty),*) => {
+        $(
+            // Add Matrix to scalar
+            impl ops::Add<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn add(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    rhs + self
                 }
+            }
+
+            // Add borrowed Matrix to scalar
+            impl ops::Add<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
 
-                output_data.push(dot_prod);
+                fn add(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    rhs + self
+                }
             }
-         }
 
-        Ok(Matrix { data: output_data, rows: self.rows, cols: rhs.cols })
-    }
-}
\ No newline at end of file
+            // Subtract Matrix from scalar
+            impl ops::Sub<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn sub(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    let mut output_data: Vec<$t> = rhs.data;
+
+                    for item in &mut output_data {
+                        *item = self - *item;
+                    }
+
+                    Matrix { data: output_data, rows: rhs.rows, cols: rhs.cols }
+                }
+            }
+
+            // Subtract borrowed Matrix from scalar
+            impl ops::Sub<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn sub(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    self - rhs.clone()
+                }
+            }
+
+            // Multiply scalar by Matrix
+            impl ops::Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    rhs * self
+                }
+            }
+
+            // Multiply scalar by borrowed Matrix
+            impl ops::Mul<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs_ops!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, Complex<f32>, Complex<f64>);
@@ -6,22 +6,34 @@ use opencl3::command_queue::{
 };
 use opencl3::context::Context;
 use opencl3::device::{
-    get_all_devices,
     Device,
+    CL_DEVICE_TYPE_ACCELERATOR,
+    CL_DEVICE_TYPE_ALL,
+    CL_DEVICE_TYPE_CPU,
     CL_DEVICE_TYPE_GPU
 };
+use opencl3::error_codes::{
+    ClError,
+    CL_DEVICE_NOT_FOUND,
+    CL_PLATFORM_NOT_FOUND_KHR
+};
 use opencl3::kernel::{
     ExecuteKernel,
     Kernel
 };
 use opencl3::memory::{
     Buffer,
+    ClMem,
     CL_MEM_READ_ONLY,
     CL_MEM_READ_WRITE
 };
+use opencl3::platform::{
+    get_platforms,
+    Platform
+};
 use opencl3::program::Program;
 use opencl3::types::{
-    cl_event,
+    CL_BLOCKING,
     CL_NON_BLOCKING
 };
 use opencl3::event::Event;
@@ -31,22 +43,90 @@ pub struct MemoryHandler {
     context: Context, // OpenCL context
     command_queue: CommandQueue, // OpenCL command queue
     kernels: Vec<Kernel>, // Vector of all compiled kernels
-    write_buffers: Vec<Buffer<f32>>, // Vector of full write buffers
-    last_write_event: Option<Event> // Last write event object
+    write_buffers: Vec<Option<Buffer<f32>>>, // Vector of full write buffers (None for free slots)
+    last_write_event: Option<Event>, // Last write event object
+    tiled_mat_mul: Option<(usize, usize)> // Kernel index and tile size of tiled mat_mul (None falls back to mat_mul)
 }
 
 use crate::Result;
 use crate::err::LAError;
 use crate::matrix::Matrix;
+use crate::backend::{
+    occupied,
+    place,
+    bind_in_order,
+    Backend,
+    BoundArg,
+    BuiltinOp
+};
+use crate::elementwise::Axis;
+use crate::calculator::{
+    DeviceInfo,
+    DeviceType
+};
 
 impl MemoryHandler {
-    pub fn new(program_source: &str, kernel_names: Vec<&str>) -> Result<MemoryHandler> {
-        // Get devices and create device object
-        let device_id = *get_all_devices(CL_DEVICE_TYPE_GPU)?
-            .first()
-            .expect("No device found in platform");
-        let device: Device = Device::new(device_id);
-    
+    // Get every OpenCL device on every platform with its properties (empty if no platform is installed)
+    pub fn all_devices() -> Result<Vec<(DeviceInfo, Device)>> {
+        let platforms: Vec<Platform> = match get_platforms() {
+            Ok(platforms) => platforms,
+            Err(ClError(CL_PLATFORM_NOT_FOUND_KHR)) => return Ok(Vec::new()),
+            Err(error) => return Err(error.into())
+        };
+
+        let mut devices: Vec<(DeviceInfo, Device)> = Vec::new();
+
+        for (platform_index, platform) in platforms.iter().enumerate() {
+            let platform_name: String = platform.name().unwrap_or_default();
+
+            // ICD loaders can expose platforms without any devices
+            let device_ids = match platform.get_devices(CL_DEVICE_TYPE_ALL) {
+                Ok(device_ids) => device_ids,
+                Err(ClError(CL_DEVICE_NOT_FOUND)) => continue,
+                Err(error) => return Err(error.into())
+            };
+
+            for (device_index, device_id) in device_ids.into_iter().enumerate() {
+                let device: Device = Device::new(device_id);
+
+                // Devices that fail to report their properties are skipped
+                if let Ok(info) = MemoryHandler::device_info(platform_index, &platform_name, device_index, &device) {
+                    devices.push((info, device));
+                }
+            }
+        }
+
+        Ok(devices)
+    }
+
+    // Query properties of device (private function)
+    fn device_info(platform_index: usize, platform_name: &str, device_index: usize, device: &Device) -> Result<DeviceInfo> {
+        let device_type: DeviceType = match device.dev_type()? {
+            dev_type if dev_type & CL_DEVICE_TYPE_GPU != 0 => DeviceType::Gpu,
+            dev_type if dev_type & CL_DEVICE_TYPE_CPU != 0 => DeviceType::Cpu,
+            dev_type if dev_type & CL_DEVICE_TYPE_ACCELERATOR != 0 => DeviceType::Accelerator,
+            _ => DeviceType::Other
+        };
+
+        let info: DeviceInfo = DeviceInfo {
+            platform_index,
+            platform_name: platform_name.to_string(),
+            device_index,
+            name: device.name()?,
+            vendor: device.vendor()?,
+            version: device.version()?,
+            device_type,
+            compute_units: device.max_compute_units()?,
+            global_mem_size: device.global_mem_size()?,
+            local_mem_size: device.local_mem_size()?,
+            max_work_group_size: device.max_work_group_size()?
+        };
+
+        Ok(info)
+    }
+
+    // Create handler with compiled default kernels (tiled mat_mul uses tiles of at most max_tile_size if given)
+    pub fn new(device: Device, program_source: &str, kernel_names: Vec<&str>, max_tile_size: Option<usize>) -> Result<MemoryHandler> {
         // Create context object from device
         let context: Context = Context::from_device(&device)?;
     
@@ -58,11 +138,7 @@ impl MemoryHandler {
         )?;
     
         // Compile program from source
-        let program: Program = Program::create_and_build_from_source(
-            &context,
-            program_source,
-            ""
-        ).expect("Failed to build program");
+        let program: Program = build_program(&context, program_source, "", &kernel_names.join(", "))?;
     
         // Initialize empty kernel vector
         let mut kernel_vector: Vec<Kernel> = Vec::with_capacity(kernel_names.len());
@@ -75,21 +151,63 @@ impl MemoryHandler {
         }
     
         // Create empty buffer vector
-        let buffer_vector: Vec<Buffer<f32>> = Vec::with_capacity(super::INIT_MEMORY_CAPACITY);
+        let buffer_vector: Vec<Option<Buffer<f32>>> = Vec::with_capacity(super::INIT_MEMORY_CAPACITY);
     
-        // Create and return new Memory Handler
-        let output: MemoryHandler = MemoryHandler {
+        // Create new Memory Handler
+        let mut output: MemoryHandler = MemoryHandler {
             context: context,
             command_queue: queue,
             kernels: kernel_vector,
             write_buffers: buffer_vector,
-            last_write_event: None
+            last_write_event: None,
+            tiled_mat_mul: None
         };
+
+        // Compile tiled mat_mul sized for this device, keeping naive mat_mul if it is unusable
+        output.tiled_mat_mul = output.build_tiled_mat_mul(&device, max_tile_size.unwrap_or(usize::MAX));
+
         Ok(output)
     }
 
-    // Create buffer and store matrix in buffer
-    pub fn store_matrix(&mut self, matrix: Matrix) -> Result<usize> {
+    // Compile tiled mat_mul with the largest tile up to max_tile_size the device supports (None if no tile fits or builds)
+    fn build_tiled_mat_mul(&mut self, device: &Device, max_tile_size: usize) -> Option<(usize, usize)> {
+        let max_work_group_size: usize = device.max_work_group_size().ok()?;
+        let max_work_item_sizes: Vec<usize> = device.max_work_item_sizes().ok()?;
+        let local_mem_size: u64 = device.local_mem_size().ok()?;
+
+        for tile_size in super::MAT_MUL_TILE_SIZES {
+            // One work-item per tile element and two tiles in local memory
+            let fits: bool = tile_size <= max_tile_size
+                && tile_size * tile_size <= max_work_group_size
+                && max_work_item_sizes.iter().take(2).all(|&size| size >= tile_size)
+                && (2 * tile_size * tile_size * std::mem::size_of::<f32>()) as u64 <= local_mem_size;
+
+            if !fits {
+                continue
+            }
+
+            let options: String = format!("-D TILE_SIZE={}", tile_size);
+            let kernel_index: usize = match self.new_kernel(super::TILED_MAT_MUL_SOURCE, super::TILED_MAT_MUL_NAME, &options) {
+                Ok(kernel_index) => kernel_index,
+                Err(_) => continue
+            };
+
+            // Register and local memory use can limit the kernel below the device work-group size
+            match self.kernels[kernel_index].get_work_group_size(device.id()) {
+                Ok(kernel_work_group_size) if kernel_work_group_size >= tile_size * tile_size => {
+                    return Some((kernel_index, tile_size))
+                },
+                _ => {
+                    self.kernels.pop();
+                }
+            }
+        }
+
+        None
+    }
+
+    // Create buffer in memory slot idx and store matrix in buffer
+    pub fn store_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
         if matrix.get_rows() * matrix.get_cols() != matrix.get_data().len() {
             return Err(LAError::MatrixMismatchError)
         }
@@ -105,35 +223,26 @@ impl MemoryHandler {
         };
 
         // Write matrix to buffer and store event
-        self.last_write_event = Some(unsafe {
-            self.command_queue.enqueue_write_buffer(
-                &mut new_write_buffer,
-                CL_NON_BLOCKING,
-                0,
-                &matrix.get_data(),
-                &[]
-            )?
-        });
+        self.last_write_event = Some(write_data(&self.command_queue, &mut new_write_buffer, &matrix.data)?);
 
         // Store full write buffer to handler memory
-        self.write_buffers.push(new_write_buffer);
+        place(&mut self.write_buffers, idx, new_write_buffer);
 
-        // Return index of new write buffer
-        let output: usize = self.write_buffers.len() - 1;
-        Ok(output)
+        Ok(())
     }
 
-    // Execute kernel and return resulting matrix
-    pub fn execute_and_read(
+    // Execute kernel and keep resulting matrix on the device in memory slot output_idx (runtime picks work-group size if local_work_sizes is None)
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute(
         &mut self,
         kernel_index: usize,
-        input_floats: Option<Vec<f32>>,
-        input_ints: Option<Vec<i32>>,
-        input_mat_idcs: Vec<usize>,
+        args: Vec<BoundArg>,
+        output_idx: usize,
         output_rows: usize,
         output_cols: usize,
-        work_sizes: Vec<usize>
-    ) -> Result<(Matrix, usize)>
+        work_sizes: Vec<usize>,
+        local_work_sizes: Option<Vec<usize>>
+    ) -> Result<()>
     {
         // Create read buffer
         let read_buffer: Buffer<f32> = unsafe {
@@ -145,96 +254,122 @@ impl MemoryHandler {
             )?
         };
 
-        // Create ExecuteKernel object and empty events vector
+        // Create ExecuteKernel object
         let mut exec_kernel: ExecuteKernel = ExecuteKernel::new(&self.kernels[kernel_index]);
-        let mut events: Vec<cl_event> = Vec::default();
-
-        // Create output vector and initilize with zeros
-        let mut output_data: Vec<f32> = vec![0.0; output_rows * output_cols];
 
         // Give ExecuteKernel the read buffer
         let mut kernel_mid_exec: &mut ExecuteKernel = unsafe {
             exec_kernel.set_arg(&read_buffer)
         };
 
-        // Give ExecuteKernel the provided floats
-        if input_floats.is_some() {
-            for write_value in input_floats.unwrap() {
-                kernel_mid_exec = unsafe {
-                    kernel_mid_exec.set_arg(&write_value)
+        // Give ExecuteKernel the arguments in order
+        for arg in args {
+            kernel_mid_exec = match arg {
+                BoundArg::Float(write_value) => unsafe { kernel_mid_exec.set_arg(&write_value) },
+                BoundArg::Int(write_value) => unsafe { kernel_mid_exec.set_arg(&write_value) },
+                BoundArg::Matrix(idx) => {
+                    let selected_buffer: &Buffer<f32> = occupied(&self.write_buffers, idx)?;
+                    unsafe { kernel_mid_exec.set_arg(selected_buffer) }
                 }
-            }
+            };
         }
 
-        // Give ExecuteKernel the provided ints
-        if input_ints.is_some() {
-            for write_value in input_ints.unwrap() {
-                kernel_mid_exec = unsafe {
-                    kernel_mid_exec.set_arg(&write_value)
-                }
-            }
+        // Give ExecuteKernel the work sizes
+        kernel_mid_exec = kernel_mid_exec.set_global_work_sizes(&work_sizes);
+        if let Some(local_sizes) = local_work_sizes.as_ref() {
+            kernel_mid_exec = kernel_mid_exec.set_local_work_sizes(local_sizes);
         }
 
-        // Collect selected write buffers into separate vector
-        let selected_write_buffers: Vec<&Buffer<f32>> = input_mat_idcs
-            .iter()
-            .map(|idx| &self.write_buffers[*idx])
-            .collect::<Vec<_>>();
-
-        // Give ExecuteKernel the selected matrices
-        for selected_buffer in selected_write_buffers {
-            kernel_mid_exec = unsafe {
-                kernel_mid_exec.set_arg(selected_buffer)
-            };
+        // Wait on the most recent write or kernel if there is one (kernels without matrix arguments can run first)
+        if let Some(last_write_event) = self.last_write_event.as_ref() {
+            kernel_mid_exec = kernel_mid_exec.set_wait_event(last_write_event);
         }
 
-        // Unwrap Option from before to reference later
-        let last_write_event: &Event = self.last_write_event.as_ref().unwrap();
-
         // Finalize kernel execution and store final event
         let kernel_event: Event = unsafe {
-            kernel_mid_exec
-                .set_global_work_sizes(&work_sizes)
-                .set_wait_event(last_write_event)
-                .enqueue_nd_range(&self.command_queue)?
+            kernel_mid_exec.enqueue_nd_range(&self.command_queue)?
         };
 
-        // Store raw kernel event in events vector
-        events.push(kernel_event.get());
+        // Store new calculated buffer in handler memory
+        place(&mut self.write_buffers, output_idx, read_buffer);
+
+        // Update most recent write event
+        self.last_write_event = Some(kernel_event);
+
+        Ok(())
+    }
+
+    // Read buffer in memory slot idx back to the host as a rows x cols matrix
+    pub fn read_matrix(&mut self, idx: usize, rows: usize, cols: usize) -> Result<Matrix> {
+        let read_buffer: &Buffer<f32> = occupied(&self.write_buffers, idx)?;
 
-        // Read from buffer and store in output vector
+        // Create output vector and initilize with zeros
+        let mut output_data: Vec<f32> = vec![0.0; rows * cols];
+
+        // Read from buffer (in-order queue runs this after every kernel writing the buffer)
         let read_event: Event = unsafe {
             self.command_queue.enqueue_read_buffer(
-                &read_buffer,
+                read_buffer,
                 CL_NON_BLOCKING,
                 0,
                 &mut output_data,
-                &events
+                &[]
             )?
         };
 
         // Wait for read event to finish
         read_event.wait()?;
 
-        // Store new calculated buffer in handler memory
-        self.write_buffers.push(read_buffer);
+        let output: Matrix = Matrix::new(output_data, rows, cols)?;
+        Ok(output)
+    }
 
-        // Update most recent write event
-        self.last_write_event = Some(kernel_event);
+    // Release write buffer in memory slot idx
+    pub fn free_matrix(&mut self, idx: usize) -> Result<()> {
+        occupied(&self.write_buffers, idx)?;
+        self.write_buffers[idx] = None;
 
-        // Create and return output matrix and memory index
-        let output: Matrix = Matrix::new(output_data, output_rows, output_cols)?;
-        let output_idx: usize = self.write_buffers.len() - 1;
-        Ok((output, output_idx))
+        Ok(())
     }
 
-    pub fn new_kernel(&mut self, program_source: &str, kernel_name: &str) -> Result<usize> {
+    // Write matrix into existing buffer, or a new buffer in the same slot if the element count changed
+    pub fn overwrite_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
+        if matrix.get_rows() * matrix.get_cols() != matrix.get_data().len() {
+            return Err(LAError::MatrixMismatchError)
+        }
+
+        let buffer_len: usize = occupied(&self.write_buffers, idx)?.size()? / std::mem::size_of::<f32>();
+
+        if buffer_len != matrix.get_data().len() {
+            let new_write_buffer: Buffer<f32> = unsafe {
+                Buffer::<f32>::create(
+                    &self.context,
+                    CL_MEM_READ_ONLY,
+                    matrix.get_data().len(),
+                    ptr::null_mut()
+                )?
+            };
+
+            self.write_buffers[idx] = Some(new_write_buffer);
+        }
+
+        // Existing slot was checked above so the buffer is present
+        let write_buffer: &mut Buffer<f32> = self.write_buffers[idx].as_mut().ok_or(LAError::MemoryError)?;
+
+        // Write matrix to buffer and store event (in-order queue runs this after pending reads of the old data)
+        self.last_write_event = Some(write_data(&self.command_queue, write_buffer, &matrix.data)?);
+
+        Ok(())
+    }
+
+    // Release every write buffer
+    pub fn clear(&mut self) {
+        self.write_buffers.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    pub fn new_kernel(&mut self, program_source: &str, kernel_name: &str, options: &str) -> Result<usize> {
         // Compile program from source
-        let program: Program = Program::create_and_build_from_source(
-            &self.context,
-            program_source,
-            ""
-        ).expect("Failed to build program");
+        let program: Program = build_program(&self.context, program_source, options, kernel_name)?;
 
         let kernel: Kernel = Kernel::create(&program, kernel_name)?;
 
@@ -242,4 +377,182 @@ impl MemoryHandler {
 
         Ok(self.kernels.len() - 1)
     }
-}
\ No newline at end of file
+}
+
+// Compile program for every device in context, returning the build log on failure
+fn build_program(context: &Context, program_source: &str, options: &str, kernel_name: &str) -> Result<Program> {
+    let mut program: Program = Program::create_from_source(context, program_source)?;
+
+    if program.build(context.devices(), options).is_err() {
+        let build_log: String = context.devices()
+            .iter()
+            .filter_map(|device_id| program.get_build_log(*device_id).ok())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        return Err(LAError::KernelBuildError { kernel_name: kernel_name.to_string(), build_log })
+    }
+
+    Ok(program)
+}
+
+impl Backend for MemoryHandler {
+    fn store_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
+        MemoryHandler::store_matrix(self, idx, matrix)
+    }
+
+    fn free_matrix(&mut self, idx: usize) -> Result<()> {
+        MemoryHandler::free_matrix(self, idx)
+    }
+
+    fn overwrite_matrix(&mut self, idx: usize, matrix: Matrix) -> Result<()> {
+        MemoryHandler::overwrite_matrix(self, idx, matrix)
+    }
+
+    fn clear(&mut self) {
+        MemoryHandler::clear(self)
+    }
+
+    fn mat_mul(
+        &mut self,
+        left_idx: usize,
+        right_idx: usize,
+        output_idx: usize,
+        rows: usize,
+        interm: usize,
+        cols: usize
+    ) -> Result<()>
+    {
+        // Tiled kernel covers the output with whole tiles and skips work-items past the edges
+        if let Some((kernel_index, tile_size)) = self.tiled_mat_mul {
+            return self.execute(
+                kernel_index,
+                bind_in_order(None, Some(vec![rows as i32, cols as i32, interm as i32]), vec![left_idx, right_idx]),
+                output_idx,
+                rows,
+                cols,
+                vec![rows.div_ceil(tile_size) * tile_size, cols.div_ceil(tile_size) * tile_size],
+                Some(vec![tile_size, tile_size])
+            )
+        }
+
+        // Built-in mat_mul kernel is always compiled first
+        self.execute(
+            0,
+            bind_in_order(None, Some(vec![cols as i32, interm as i32]), vec![left_idx, right_idx]),
+            output_idx,
+            rows,
+            cols,
+            vec![rows, cols],
+            None
+        )
+    }
+
+    fn builtin(
+        &mut self,
+        op: BuiltinOp,
+        input_idcs: &[usize],
+        output_idx: usize,
+        rows: usize,
+        cols: usize
+    ) -> Result<()>
+    {
+        if input_idcs.len() != op.n_inputs() {
+            return Err(LAError::ArgumentError)
+        }
+
+        // Default kernel name, scalar arguments, int arguments and work sizes of each operation
+        let (kernel_name, input_floats, input_ints, work_sizes) = match op {
+            BuiltinOp::Add => ("mat_add", None, None, vec![rows * cols]),
+            BuiltinOp::Sub => ("mat_sub", None, None, vec![rows * cols]),
+            BuiltinOp::Hadamard => ("mat_hadamard", None, None, vec![rows * cols]),
+            BuiltinOp::AddScalar(scalar) => ("scalar_add", Some(vec![scalar]), None, vec![rows * cols]),
+            BuiltinOp::MulScalar(scalar) => ("scalar_mul", Some(vec![scalar]), None, vec![rows * cols]),
+            BuiltinOp::Transpose => ("transpose", None, Some(vec![rows as i32, cols as i32]), vec![rows, cols]),
+            BuiltinOp::Sum(Axis::Row) => ("sum_rows", None, Some(vec![cols as i32]), vec![rows]),
+            BuiltinOp::Sum(Axis::Col) => ("sum_cols", None, Some(vec![rows as i32, cols as i32]), vec![cols]),
+            BuiltinOp::Exp => ("unary_exp", None, None, vec![rows * cols]),
+            BuiltinOp::Ln => ("unary_log", None, None, vec![rows * cols]),
+            BuiltinOp::Sqrt => ("unary_sqrt", None, None, vec![rows * cols]),
+            BuiltinOp::Tanh => ("unary_tanh", None, None, vec![rows * cols])
+        };
+
+        // Default kernels are compiled first, in PROGRAM_LIST order
+        let kernel_index: usize = super::PROGRAM_LIST
+            .iter()
+            .position(|name| *name == kernel_name)
+            .ok_or(LAError::IndexError)?;
+
+        let (output_rows, output_cols) = op.output_shape(rows, cols);
+
+        self.execute(
+            kernel_index,
+            bind_in_order(input_floats, input_ints, input_idcs.to_vec()),
+            output_idx,
+            output_rows,
+            output_cols,
+            work_sizes,
+            None
+        )
+    }
+
+    fn new_kernel(&mut self, program_source: &str, kernel_name: &str, options: &str) -> Result<usize> {
+        MemoryHandler::new_kernel(self, program_source, kernel_name, options)
+    }
+
+    fn read_matrix(&mut self, idx: usize, rows: usize, cols: usize) -> Result<Matrix> {
+        MemoryHandler::read_matrix(self, idx, rows, cols)
+    }
+
+    fn mat_mul_tile_size(&self) -> Option<usize> {
+        self.tiled_mat_mul.map(|(_, tile_size)| tile_size)
+    }
+
+    fn kernel_arg_types(&self, kernel_index: usize) -> Result<Vec<Option<String>>> {
+        let kernel: &Kernel = self.kernels.get(kernel_index).ok_or(LAError::IndexError)?;
+
+        // Type names are only reported for programs built with -cl-kernel-arg-info
+        let arg_types: Vec<Option<String>> = (0..kernel.num_args()?)
+            .map(|arg_index| kernel.get_arg_type_name(arg_index).ok())
+            .collect();
+
+        Ok(arg_types)
+    }
+
+    fn execute(
+        &mut self,
+        kernel_index: usize,
+        args: Vec<BoundArg>,
+        output_idx: usize,
+        output_rows: usize,
+        output_cols: usize,
+        work_sizes: Vec<usize>
+    ) -> Result<()>
+    {
+        MemoryHandler::execute(
+            self,
+            kernel_index,
+            args,
+            output_idx,
+            output_rows,
+            output_cols,
+            work_sizes,
+            None
+        )
+    }
+}
+
+// Write host data to buffer, blocking until the runtime has copied it so data can be dropped afterwards (private function)
+fn write_data(command_queue: &CommandQueue, buffer: &mut Buffer<f32>, data: &[f32]) -> Result<Event> {
+    let write_event: Event = unsafe {
+        command_queue.enqueue_write_buffer(
+            buffer,
+            CL_BLOCKING,
+            0,
+            data,
+            &[]
+        )?
+    };
+
+    Ok(write_event)
+}
@@ -0,0 +1,70 @@
+//! Matrix norms, trace, inner products and approximate comparison
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::{
+    Real,
+    Scalar
+};
+
+impl<T: Scalar> Matrix<T> {
+    /// Return sum of diagonal elements of square matrix
+    pub fn trace(&self) -> Result<T> {
+        if self.rows != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        Ok((0..self.rows).map(|idx| self.data[idx * self.cols + idx]).sum())
+    }
+
+    /// Return Frobenius inner product sum(conj(self[i, j]) * rhs[i, j]) (dot product for vectors)
+    pub fn inner(&self, rhs: &Matrix<T>) -> Result<T> {
+        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
+            return Err(LAError::SizeError)
+        }
+
+        Ok(self.data.iter().zip(&rhs.data).map(|(&lhs_value, &rhs_value)| lhs_value.conj() * rhs_value).sum())
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Return Frobenius norm (Euclidean norm for vectors)
+    pub fn norm_fro(&self) -> T {
+        self.data.iter().map(|&value| value * value).sum::<T>().sqrt()
+    }
+
+    /// Return 1-norm, the largest absolute column sum (vector 1-norm for column vectors)
+    pub fn norm_1(&self) -> T {
+        (0..self.cols)
+            .map(|col| (0..self.rows).map(|row| self.data[row * self.cols + col].abs()).sum::<T>())
+            .fold(T::zero(), T::max)
+    }
+
+    /// Return infinity norm, the largest absolute row sum (vector max norm for column vectors)
+    pub fn norm_inf(&self) -> T {
+        (0..self.rows)
+            .map(|row| self.data[row * self.cols..(row + 1) * self.cols].iter().map(|&value| value.abs()).sum::<T>())
+            .fold(T::zero(), T::max)
+    }
+
+    /// Return spectral norm, the largest singular value (Euclidean norm for vectors)
+    pub fn norm_2(&self) -> Result<T> {
+        if self.data.is_empty() {
+            return Ok(T::zero())
+        }
+
+        Ok(self.svd()?.get_singular_values().into_iter().fold(T::zero(), T::max))
+    }
+
+    /// Check whether every element satisfies |self - other| <= abs_tol + rel_tol * max(|self|, |other|) (false if dimensions differ)
+    pub fn approx_eq(&self, other: &Matrix<T>, rel_tol: T, abs_tol: T) -> bool {
+        if (self.rows != other.rows) || (self.cols != other.cols) {
+            return false
+        }
+
+        self.data.iter().zip(&other.data).all(|(&lhs_value, &rhs_value)| {
+            (lhs_value - rhs_value).abs() <= abs_tol + rel_tol * lhs_value.abs().max(rhs_value.abs())
+        })
+    }
+}
@@ -0,0 +1,137 @@
+//! Numeric traits describing the element types Matrix can hold
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter;
+use std::ops;
+
+/// Trait for numeric types that can be stored in a Matrix and used with its arithmetic operators
+pub trait Scalar:
+    Copy
+    + Send
+    + Sync
+    + fmt::Debug
+    + PartialEq
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::AddAssign
+    + ops::SubAssign
+    + ops::MulAssign
+    + iter::Sum
+{
+    /// Get additive identity
+    fn zero() -> Self;
+    /// Get multiplicative identity
+    fn one() -> Self;
+    /// Get complex conjugate (identity for real types)
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+/// Trait for real floating point types supporting matrix decompositions
+pub trait Real:
+    Scalar
+    + Default
+    + PartialOrd
+    + ops::Neg<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::DivAssign
+{
+    /// Machine epsilon
+    const EPSILON: Self;
+    /// Positive infinity
+    const INFINITY: Self;
+
+    /// Convert from f64 (rounding if necessary)
+    fn from_f64(value: f64) -> Self;
+    /// Get absolute value
+    fn abs(self) -> Self;
+    /// Get square root
+    fn sqrt(self) -> Self;
+    /// Get natural logarithm
+    fn ln(self) -> Self;
+    /// Get sign (1 for positive values and +0, -1 for negative values and -0)
+    fn signum(self) -> Self;
+    /// Get larger of two values
+    fn max(self, other: Self) -> Self;
+    /// Check whether value is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// Get total ordering between two values
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+// Implement Scalar for primitive integer types
+macro_rules! impl_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> $t {
+                    0
+                }
+
+                fn one() -> $t {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+// Implement Scalar and Real for primitive floating point types
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> $t {
+                    0.0
+                }
+
+                fn one() -> $t {
+                    1.0
+                }
+            }
+
+            impl Real for $t {
+                const EPSILON: $t = $t::EPSILON;
+                const INFINITY: $t = $t::INFINITY;
+
+                fn from_f64(value: f64) -> $t {
+                    value as $t
+                }
+
+                fn abs(self) -> $t {
+                    $t::abs(self)
+                }
+
+                fn sqrt(self) -> $t {
+                    $t::sqrt(self)
+                }
+
+                fn ln(self) -> $t {
+                    $t::ln(self)
+                }
+
+                fn signum(self) -> $t {
+                    $t::signum(self)
+                }
+
+                fn max(self, other: $t) -> $t {
+                    $t::max(self, other)
+                }
+
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+
+                fn total_cmp(&self, other: &$t) -> Ordering {
+                    $t::total_cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_real!(f32, f64);
@@ -0,0 +1,193 @@
+//! Compressed sparse column matrix
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+use super::compress;
+use super::csr::CsrMatrix;
+
+/// Sparse matrix stored in compressed sparse column (CSC) format
+#[derive(Debug, Clone)]
+pub struct CscMatrix<T = f32> {
+    pub(crate) col_ptr: Vec<usize>, // Offsets into row_idx/values where each column starts (length cols + 1)
+    pub(crate) row_idx: Vec<usize>, // Row index of each stored value
+    pub(crate) values: Vec<T>, // Stored values, column by column
+    pub(crate) rows: usize, // Number of rows
+    pub(crate) cols: usize // Number of columns
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    /// Create new CSC matrix from raw arrays (includes checking for consistent structure)
+    pub fn new(
+        values: Vec<T>,
+        row_idx: Vec<usize>,
+        col_ptr: Vec<usize>,
+        rows: usize,
+        cols: usize
+    ) -> Result<CscMatrix<T>>
+    {
+        if col_ptr.len() != cols + 1
+            || col_ptr[0] != 0
+            || col_ptr[cols] != values.len()
+            || row_idx.len() != values.len()
+            || col_ptr.windows(2).any(|pair| pair[0] > pair[1])
+            || row_idx.iter().any(|&row| row >= rows)
+        {
+            return Err(LAError::MatrixMismatchError)
+        }
+
+        Ok(CscMatrix { col_ptr, row_idx, values, rows, cols })
+    }
+
+    /// Create CSC matrix from (row, col, value) triplets, summing duplicate entries
+    pub fn from_triplets(triplets: &[(usize, usize, T)], rows: usize, cols: usize) -> Result<CscMatrix<T>> {
+        let col_major: Vec<(usize, usize, T)> = triplets
+            .iter()
+            .map(|&(row, col, value)| (col, row, value))
+            .collect();
+        let (col_ptr, row_idx, values) = compress(cols, rows, col_major)?;
+
+        Ok(CscMatrix { col_ptr, row_idx, values, rows, cols })
+    }
+
+    /// Create CSC matrix from the nonzero entries of a dense matrix
+    pub fn from_dense(matrix: &Matrix<T>) -> CscMatrix<T> {
+        let mut col_ptr: Vec<usize> = Vec::with_capacity(matrix.cols + 1);
+        let mut row_idx: Vec<usize> = Vec::default();
+        let mut values: Vec<T> = Vec::default();
+
+        col_ptr.push(0);
+        for col in 0..matrix.cols {
+            for row in 0..matrix.rows {
+                let value: T = matrix.data[row * matrix.cols + col];
+                if value != T::zero() {
+                    row_idx.push(row);
+                    values.push(value);
+                }
+            }
+            col_ptr.push(values.len());
+        }
+
+        CscMatrix { col_ptr, row_idx, values, rows: matrix.rows, cols: matrix.cols }
+    }
+
+    /// Convert to dense matrix
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data: Vec<T> = vec![T::zero(); self.rows * self.cols];
+
+        for col in 0..self.cols {
+            for entry in self.col_ptr[col]..self.col_ptr[col + 1] {
+                data[self.row_idx[entry] * self.cols + col] += self.values[entry];
+            }
+        }
+
+        Matrix { data, rows: self.rows, cols: self.cols }
+    }
+
+    /// Convert to compressed sparse row format
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        // CSC arrays of A are the CSR arrays of A^T, so transposing them gives CSR arrays of A
+        let as_transpose: CsrMatrix<T> = CsrMatrix {
+            row_ptr: self.col_ptr.clone(),
+            col_idx: self.row_idx.clone(),
+            values: self.values.clone(),
+            rows: self.cols,
+            cols: self.rows
+        };
+
+        as_transpose.transpose()
+    }
+
+    /// Get number of rows in matrix
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get number of columns in matrix
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Get stored values, column by column
+    pub fn get_values(&self) -> Vec<T> {
+        self.values.clone()
+    }
+
+    /// Get row index of each stored value
+    pub fn get_row_idx(&self) -> Vec<usize> {
+        self.row_idx.clone()
+    }
+
+    /// Get offsets where each column starts in the stored values
+    pub fn get_col_ptr(&self) -> Vec<usize> {
+        self.col_ptr.clone()
+    }
+
+    /// Get value at row, col index (zero if not stored)
+    pub fn get(&self, row: usize, col: usize) -> Result<T> {
+        if row >= self.rows || col >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        let value: T = (self.col_ptr[col]..self.col_ptr[col + 1])
+            .filter(|&entry| self.row_idx[entry] == row)
+            .map(|entry| self.values[entry])
+            .sum();
+
+        Ok(value)
+    }
+
+    /// Return transpose of self
+    pub fn transpose(&self) -> CscMatrix<T> {
+        // CSR arrays of A are the CSC arrays of A^T
+        let csr: CsrMatrix<T> = self.to_csr();
+
+        CscMatrix { col_ptr: csr.row_ptr, row_idx: csr.col_idx, values: csr.values, rows: self.cols, cols: self.rows }
+    }
+
+    /// Multiply self by dense vector
+    pub fn mul_vec(&self, vector: &[T]) -> Result<Vec<T>> {
+        if vector.len() != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let mut output: Vec<T> = vec![T::zero(); self.rows];
+        for (col, &x) in vector.iter().enumerate() {
+            for entry in self.col_ptr[col]..self.col_ptr[col + 1] {
+                output[self.row_idx[entry]] += self.values[entry] * x;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Multiply self by dense matrix
+    pub fn mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if rhs.rows != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n_cols: usize = rhs.cols;
+        let mut data: Vec<T> = vec![T::zero(); self.rows * n_cols];
+
+        for inner in 0..self.cols {
+            for entry in self.col_ptr[inner]..self.col_ptr[inner + 1] {
+                let value: T = self.values[entry];
+                let row: usize = self.row_idx[entry];
+
+                for col in 0..n_cols {
+                    data[row * n_cols + col] += value * rhs.data[inner * n_cols + col];
+                }
+            }
+        }
+
+        Ok(Matrix { data, rows: self.rows, cols: n_cols })
+    }
+}
@@ -0,0 +1,205 @@
+//! Compressed sparse row matrix
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+use super::compress;
+use super::csc::CscMatrix;
+
+/// Sparse matrix stored in compressed sparse row (CSR) format
+#[derive(Debug, Clone)]
+pub struct CsrMatrix<T = f32> {
+    pub(crate) row_ptr: Vec<usize>, // Offsets into col_idx/values where each row starts (length rows + 1)
+    pub(crate) col_idx: Vec<usize>, // Column index of each stored value
+    pub(crate) values: Vec<T>, // Stored values, row by row
+    pub(crate) rows: usize, // Number of rows
+    pub(crate) cols: usize // Number of columns
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    /// Create new CSR matrix from raw arrays (includes checking for consistent structure)
+    pub fn new(
+        values: Vec<T>,
+        col_idx: Vec<usize>,
+        row_ptr: Vec<usize>,
+        rows: usize,
+        cols: usize
+    ) -> Result<CsrMatrix<T>>
+    {
+        if row_ptr.len() != rows + 1
+            || row_ptr[0] != 0
+            || row_ptr[rows] != values.len()
+            || col_idx.len() != values.len()
+            || row_ptr.windows(2).any(|pair| pair[0] > pair[1])
+            || col_idx.iter().any(|&col| col >= cols)
+        {
+            return Err(LAError::MatrixMismatchError)
+        }
+
+        Ok(CsrMatrix { row_ptr, col_idx, values, rows, cols })
+    }
+
+    /// Create CSR matrix from (row, col, value) triplets, summing duplicate entries
+    pub fn from_triplets(triplets: &[(usize, usize, T)], rows: usize, cols: usize) -> Result<CsrMatrix<T>> {
+        let (row_ptr, col_idx, values) = compress(rows, cols, triplets.to_vec())?;
+
+        Ok(CsrMatrix { row_ptr, col_idx, values, rows, cols })
+    }
+
+    /// Create CSR matrix from the nonzero entries of a dense matrix
+    pub fn from_dense(matrix: &Matrix<T>) -> CsrMatrix<T> {
+        let mut row_ptr: Vec<usize> = Vec::with_capacity(matrix.rows + 1);
+        let mut col_idx: Vec<usize> = Vec::default();
+        let mut values: Vec<T> = Vec::default();
+
+        row_ptr.push(0);
+        for row in 0..matrix.rows {
+            for col in 0..matrix.cols {
+                let value: T = matrix.data[row * matrix.cols + col];
+                if value != T::zero() {
+                    col_idx.push(col);
+                    values.push(value);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+
+        CsrMatrix { row_ptr, col_idx, values, rows: matrix.rows, cols: matrix.cols }
+    }
+
+    /// Convert to dense matrix
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data: Vec<T> = vec![T::zero(); self.rows * self.cols];
+
+        for row in 0..self.rows {
+            for entry in self.row_ptr[row]..self.row_ptr[row + 1] {
+                data[row * self.cols + self.col_idx[entry]] += self.values[entry];
+            }
+        }
+
+        Matrix { data, rows: self.rows, cols: self.cols }
+    }
+
+    /// Convert to compressed sparse column format
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let nnz: usize = self.nnz();
+
+        // Count entries per column and convert to offsets
+        let mut col_ptr: Vec<usize> = vec![0; self.cols + 1];
+        for &col in &self.col_idx {
+            col_ptr[col + 1] += 1;
+        }
+        for col in 0..self.cols {
+            col_ptr[col + 1] += col_ptr[col];
+        }
+
+        // Scatter entries into their columns, rows stay sorted since rows are visited in order
+        let mut next: Vec<usize> = col_ptr[..self.cols].to_vec();
+        let mut row_idx: Vec<usize> = vec![0; nnz];
+        let mut values: Vec<T> = vec![T::zero(); nnz];
+        for row in 0..self.rows {
+            for entry in self.row_ptr[row]..self.row_ptr[row + 1] {
+                let col: usize = self.col_idx[entry];
+                row_idx[next[col]] = row;
+                values[next[col]] = self.values[entry];
+                next[col] += 1;
+            }
+        }
+
+        CscMatrix { col_ptr, row_idx, values, rows: self.rows, cols: self.cols }
+    }
+
+    /// Get number of rows in matrix
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get number of columns in matrix
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Get stored values, row by row
+    pub fn get_values(&self) -> Vec<T> {
+        self.values.clone()
+    }
+
+    /// Get column index of each stored value
+    pub fn get_col_idx(&self) -> Vec<usize> {
+        self.col_idx.clone()
+    }
+
+    /// Get offsets where each row starts in the stored values
+    pub fn get_row_ptr(&self) -> Vec<usize> {
+        self.row_ptr.clone()
+    }
+
+    /// Get value at row, col index (zero if not stored)
+    pub fn get(&self, row: usize, col: usize) -> Result<T> {
+        if row >= self.rows || col >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        let value: T = (self.row_ptr[row]..self.row_ptr[row + 1])
+            .filter(|&entry| self.col_idx[entry] == col)
+            .map(|entry| self.values[entry])
+            .sum();
+
+        Ok(value)
+    }
+
+    /// Return transpose of self
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        // CSC arrays of A are the CSR arrays of A^T
+        let csc: CscMatrix<T> = self.to_csc();
+
+        CsrMatrix { row_ptr: csc.col_ptr, col_idx: csc.row_idx, values: csc.values, rows: self.cols, cols: self.rows }
+    }
+
+    /// Multiply self by dense vector
+    pub fn mul_vec(&self, vector: &[T]) -> Result<Vec<T>> {
+        if vector.len() != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let output: Vec<T> = (0..self.rows)
+            .map(|row| {
+                (self.row_ptr[row]..self.row_ptr[row + 1])
+                    .map(|entry| self.values[entry] * vector[self.col_idx[entry]])
+                    .sum()
+            })
+            .collect();
+
+        Ok(output)
+    }
+
+    /// Multiply self by dense matrix
+    pub fn mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if rhs.rows != self.cols {
+            return Err(LAError::SizeError)
+        }
+
+        let n_cols: usize = rhs.cols;
+        let mut data: Vec<T> = vec![T::zero(); self.rows * n_cols];
+
+        for row in 0..self.rows {
+            for entry in self.row_ptr[row]..self.row_ptr[row + 1] {
+                let value: T = self.values[entry];
+                let rhs_row: usize = self.col_idx[entry];
+
+                for col in 0..n_cols {
+                    data[row * n_cols + col] += value * rhs.data[rhs_row * n_cols + col];
+                }
+            }
+        }
+
+        Ok(Matrix { data, rows: self.rows, cols: n_cols })
+    }
+}
@@ -0,0 +1,48 @@
+//! Module organizing compressed sparse matrix formats
+
+pub mod csr;
+pub mod csc;
+
+use crate::Result;
+use crate::err::LAError;
+use crate::scalar::Scalar;
+
+// Compress (major, minor, value) triplets into pointer, index and value arrays, summing duplicates (private function)
+fn compress<T: Scalar>(
+    n_major: usize,
+    n_minor: usize,
+    mut entries: Vec<(usize, usize, T)>
+) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)>
+{
+    if entries.iter().any(|&(major, minor, _)| major >= n_major || minor >= n_minor) {
+        return Err(LAError::IndexError)
+    }
+
+    entries.sort_by_key(|&(major, minor, _)| (major, minor));
+
+    let mut ptr: Vec<usize> = vec![0; n_major + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut values: Vec<T> = Vec::with_capacity(entries.len());
+    let mut last: Option<(usize, usize)> = None;
+
+    for (major, minor, value) in entries {
+        if last == Some((major, minor)) {
+            // Sum duplicate entries
+            let duplicate_idx: usize = values.len() - 1;
+            values[duplicate_idx] += value;
+            continue;
+        }
+
+        indices.push(minor);
+        values.push(value);
+        ptr[major + 1] += 1;
+        last = Some((major, minor));
+    }
+
+    // Convert per-line counts into offsets
+    for idx in 0..n_major {
+        ptr[idx + 1] += ptr[idx];
+    }
+
+    Ok((ptr, indices, values))
+}
@@ -0,0 +1,408 @@
+//! Borrowed strided views into rows, columns and blocks of a Matrix
+
+use std::ops;
+
+use crate::Result;
+use crate::err::LAError;
+use crate::matmul;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+/// Read-only view into a rectangular, possibly strided, part of a Matrix
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T = f32> {
+    data: &'a [T], // Parent data starting at the first viewed element
+    rows: usize, // Number of rows in view
+    cols: usize, // Number of columns in view
+    row_stride: usize, // Distance in parent data between consecutive rows
+    col_stride: usize // Distance in parent data between consecutive columns
+}
+
+/// Mutable view into a rectangular, possibly strided, part of a Matrix
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T = f32> {
+    data: &'a mut [T], // Parent data starting at the first viewed element
+    rows: usize, // Number of rows in view
+    cols: usize, // Number of columns in view
+    row_stride: usize, // Distance in parent data between consecutive rows
+    col_stride: usize // Distance in parent data between consecutive columns
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    /// Get number of rows in view
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get number of columns in view
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get value at row, col index of view
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Result<T> {
+        if row_idx >= self.rows || col_idx >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        Ok(self.data[row_idx * self.row_stride + col_idx * self.col_stride])
+    }
+
+    /// Return transposed view without copying
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride
+        }
+    }
+
+    /// Copy viewed elements into a new Matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut output_data: Vec<T> = Vec::with_capacity(self.rows * self.cols);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                output_data.push(self.data[row * self.row_stride + col * self.col_stride]);
+            }
+        }
+
+        Matrix { data: output_data, rows: self.rows, cols: self.cols }
+    }
+
+    // Combine each element with the matching element of rhs into a new Matrix (private function)
+    fn zip_map<F: Fn(T, T) -> T>(&self, rhs: &MatrixView<T>, combine: F) -> Result<Matrix<T>> {
+        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
+            return Err(LAError::SizeError)
+        }
+
+        let mut output_data: Vec<T> = Vec::with_capacity(self.rows * self.cols);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                output_data.push(combine(self[[row, col]], rhs[[row, col]]));
+            }
+        }
+
+        Ok(Matrix { data: output_data, rows: self.rows, cols: self.cols })
+    }
+
+    // Multiply view by rhs into a new Matrix through contiguous copies (private function)
+    fn mat_mul(&self, rhs: &MatrixView<T>) -> Result<Matrix<T>> {
+        matmul::mat_mul(&self.to_matrix(), &rhs.to_matrix())
+    }
+}
+
+impl<'a, T: Scalar> MatrixViewMut<'a, T> {
+    /// Get number of rows in view
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get number of columns in view
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get value at row, col index of view
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Result<T> {
+        self.as_view().get(row_idx, col_idx)
+    }
+
+    /// Get mutable reference to value at row, col index of view
+    pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Result<&mut T> {
+        if row_idx >= self.rows || col_idx >= self.cols {
+            return Err(LAError::IndexError)
+        }
+
+        Ok(&mut self.data[row_idx * self.row_stride + col_idx * self.col_stride])
+    }
+
+    /// Reborrow as read-only view
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride
+        }
+    }
+
+    /// Copy viewed elements into a new Matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+
+    /// Set every viewed element to value
+    pub fn fill(&mut self, value: T) {
+        self.map_in_place(|item| *item = value);
+    }
+
+    /// Overwrite viewed elements with the elements of source (must have same dimensions)
+    pub fn copy_from(&mut self, source: MatrixView<T>) -> Result<()> {
+        self.zip_in_place(source, |item, source_item| *item = source_item)
+    }
+
+    // Apply function to each viewed element (private function)
+    fn map_in_place<F: Fn(&mut T)>(&mut self, apply: F) {
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                apply(&mut self.data[row * self.row_stride + col * self.col_stride]);
+            }
+        }
+    }
+
+    // Combine each viewed element in place with the matching element of rhs (private function)
+    fn zip_in_place<F: Fn(&mut T, T)>(&mut self, rhs: MatrixView<T>, combine: F) -> Result<()> {
+        if (self.rows != rhs.rows) || (self.cols != rhs.cols) {
+            return Err(LAError::SizeError)
+        }
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                combine(&mut self.data[row * self.row_stride + col * self.col_stride], rhs[[row, col]]);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Check block bounds and return offset of its first element in matrix data (private function)
+fn block_offset<T>(matrix: &Matrix<T>, row_idx: usize, col_idx: usize, rows: usize, cols: usize) -> Result<usize> {
+    if row_idx + rows > matrix.rows || col_idx + cols > matrix.cols {
+        return Err(LAError::IndexError)
+    }
+
+    // Empty blocks may start one past the last element
+    Ok((row_idx * matrix.cols + col_idx).min(matrix.data.len()))
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Return view of whole matrix
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView { data: &self.data, rows: self.rows, cols: self.cols, row_stride: self.cols, col_stride: 1 }
+    }
+
+    /// Return mutable view of whole matrix
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = (self.rows, self.cols);
+
+        MatrixViewMut { data: &mut self.data, rows, cols, row_stride: cols, col_stride: 1 }
+    }
+
+    /// Return view of block with top left corner at row_idx, col_idx
+    pub fn view(&self, row_idx: usize, col_idx: usize, rows: usize, cols: usize) -> Result<MatrixView<'_, T>> {
+        let offset: usize = block_offset(self, row_idx, col_idx, rows, cols)?;
+
+        Ok(MatrixView { data: &self.data[offset..], rows, cols, row_stride: self.cols, col_stride: 1 })
+    }
+
+    /// Return mutable view of block with top left corner at row_idx, col_idx
+    pub fn view_mut(&mut self, row_idx: usize, col_idx: usize, rows: usize, cols: usize) -> Result<MatrixViewMut<'_, T>> {
+        let offset: usize = block_offset(self, row_idx, col_idx, rows, cols)?;
+        let row_stride: usize = self.cols;
+
+        Ok(MatrixViewMut { data: &mut self.data[offset..], rows, cols, row_stride, col_stride: 1 })
+    }
+
+    /// Return view of selected row (1 x cols)
+    pub fn row_view(&self, row_idx: usize) -> Result<MatrixView<'_, T>> {
+        self.view(row_idx, 0, 1, self.cols)
+    }
+
+    /// Return mutable view of selected row (1 x cols)
+    pub fn row_view_mut(&mut self, row_idx: usize) -> Result<MatrixViewMut<'_, T>> {
+        let cols: usize = self.cols;
+
+        self.view_mut(row_idx, 0, 1, cols)
+    }
+
+    /// Return view of selected column (rows x 1)
+    pub fn col_view(&self, col_idx: usize) -> Result<MatrixView<'_, T>> {
+        self.view(0, col_idx, self.rows, 1)
+    }
+
+    /// Return mutable view of selected column (rows x 1)
+    pub fn col_view_mut(&mut self, col_idx: usize) -> Result<MatrixViewMut<'_, T>> {
+        let rows: usize = self.rows;
+
+        self.view_mut(0, col_idx, rows, 1)
+    }
+}
+
+// Get view value at row, col index (panics if out of bounds)
+impl<T: Scalar> ops::Index<[usize; 2]> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        if idx[0] >= self.rows || idx[1] >= self.cols {
+            panic!("View index {:?} out of bounds for {}x{} view", idx, self.rows, self.cols);
+        }
+
+        &self.data[idx[0] * self.row_stride + idx[1] * self.col_stride]
+    }
+}
+
+// Get mutable view value at row, col index (panics if out of bounds)
+impl<T: Scalar> ops::Index<[usize; 2]> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        if idx[0] >= self.rows || idx[1] >= self.cols {
+            panic!("View index {:?} out of bounds for {}x{} view", idx, self.rows, self.cols);
+        }
+
+        &self.data[idx[0] * self.row_stride + idx[1] * self.col_stride]
+    }
+}
+
+// Set mutable view value at row, col index (panics if out of bounds)
+impl<T: Scalar> ops::IndexMut<[usize; 2]> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
+        if idx[0] >= self.rows || idx[1] >= self.cols {
+            panic!("View index {:?} out of bounds for {}x{} view", idx, self.rows, self.cols);
+        }
+
+        &mut self.data[idx[0] * self.row_stride + idx[1] * self.col_stride]
+    }
+}
+
+// Add view to view
+impl<T: Scalar> ops::Add<MatrixView<'_, T>> for MatrixView<'_, T> {
+    type Output = Result<Matrix<T>>;
+
+    fn add(self, rhs: MatrixView<T>) -> Result<Matrix<T>> {
+        self.zip_map(&rhs, |lhs_item, rhs_item| lhs_item + rhs_item)
+    }
+}
+
+// Subtract view from view
+impl<T: Scalar> ops::Sub<MatrixView<'_, T>> for MatrixView<'_, T> {
+    type Output = Result<Matrix<T>>;
+
+    fn sub(self, rhs: MatrixView<T>) -> Result<Matrix<T>> {
+        self.zip_map(&rhs, |lhs_item, rhs_item| lhs_item - rhs_item)
+    }
+}
+
+// Multiply view by view
+impl<T: Scalar> ops::Mul<MatrixView<'_, T>> for MatrixView<'_, T> {
+    type Output = Result<Matrix<T>>;
+
+    fn mul(self, rhs: MatrixView<T>) -> Result<Matrix<T>> {
+        self.mat_mul(&rhs)
+    }
+}
+
+// Negate view into a new Matrix
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.to_matrix()
+    }
+}
+
+// Implement view operators with borrowed matrices and scalars by forwarding to view-view operators
+macro_rules! impl_view_ops {
+    ($(($trait:ident, $method:ident)),*) => {
+        $(
+            impl<T: Scalar> ops::$trait<&Matrix<T>> for MatrixView<'_, T> {
+                type Output = Result<Matrix<T>>;
+
+                fn $method(self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+                    ops::$trait::$method(self, rhs.as_view())
+                }
+            }
+
+            impl<T: Scalar> ops::$trait<MatrixView<'_, T>> for &Matrix<T> {
+                type Output = Result<Matrix<T>>;
+
+                fn $method(self, rhs: MatrixView<T>) -> Result<Matrix<T>> {
+                    ops::$trait::$method(self.as_view(), rhs)
+                }
+            }
+
+            impl<T: Scalar> ops::$trait<T> for MatrixView<'_, T> {
+                type Output = Matrix<T>;
+
+                fn $method(self, rhs: T) -> Matrix<T> {
+                    ops::$trait::$method(self.to_matrix(), rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_view_ops!((Add, add), (Sub, sub), (Mul, mul));
+
+// Add scalar to viewed elements in place
+impl<T: Scalar> ops::AddAssign<T> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: T) {
+        self.map_in_place(|item| *item += rhs);
+    }
+}
+
+// Subtract scalar from viewed elements in place
+impl<T: Scalar> ops::SubAssign<T> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: T) {
+        self.map_in_place(|item| *item -= rhs);
+    }
+}
+
+// Multiply viewed elements by scalar in place
+impl<T: Scalar> ops::MulAssign<T> for MatrixViewMut<'_, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.map_in_place(|item| *item *= rhs);
+    }
+}
+
+// Add view to viewed elements in place (panics if dimensions do not match)
+impl<T: Scalar> ops::AddAssign<MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: MatrixView<T>) {
+        self.zip_in_place(rhs, |item, rhs_item| *item += rhs_item)
+            .expect("View dimensions do not match for addition");
+    }
+}
+
+// Subtract view from viewed elements in place (panics if dimensions do not match)
+impl<T: Scalar> ops::SubAssign<MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: MatrixView<T>) {
+        self.zip_in_place(rhs, |item, rhs_item| *item -= rhs_item)
+            .expect("View dimensions do not match for subtraction");
+    }
+}
+
+// Add borrowed Matrix to viewed elements in place (panics if dimensions do not match)
+impl<T: Scalar> ops::AddAssign<&Matrix<T>> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        *self += rhs.as_view();
+    }
+}
+
+// Subtract borrowed Matrix from viewed elements in place (panics if dimensions do not match)
+impl<T: Scalar> ops::SubAssign<&Matrix<T>> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        *self -= rhs.as_view();
+    }
+}
+
+// Add view to Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::AddAssign<MatrixView<'_, T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: MatrixView<T>) {
+        let mut view: MatrixViewMut<T> = self.as_view_mut();
+        view += rhs;
+    }
+}
+
+// Subtract view from Matrix in place (panics if dimensions do not match)
+impl<T: Scalar> ops::SubAssign<MatrixView<'_, T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: MatrixView<T>) {
+        let mut view: MatrixViewMut<T> = self.as_view_mut();
+        view -= rhs;
+    }
+}
@@ -23,7 +23,10 @@ fn linalg_readme() {
 
     assert_eq!(result[[1, 1]], 5.0);
 
-    use oscirs_linalg::calculator::Calculator;
+    use oscirs_linalg::calculator::{
+        Calculator,
+        MatrixHandle
+    };
     
     let mut calc: Calculator = Calculator::init()
         .expect("Failed to initialize Calculator");
@@ -36,17 +39,43 @@ fn linalg_readme() {
     let b_mat: Matrix = Matrix::new(b_vec, 3, 2)
         .expect("Failed to create Matrix B");
 
-    let a_idx: usize = calc.store_matrix(a_mat)
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat)
         .expect("Failed to store Matrix A in calculator memory");
-    let b_idx: usize = calc.store_matrix(b_mat)
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat)
         .expect("Failed to store Matrix B in calculator memory");
 
-    let (c_mat, _c_idx) = calc.mat_mul(a_idx, b_idx)
+    let (c_mat, _c_handle) = calc.mat_mul(a_handle, b_handle)
         .expect("Failed to mulitply Matrix A and Matrix B");
     
     assert_eq!(c_mat.get_data(), vec![12.0, 10.0, 30.0, 25.0], "Matrix C data not as expected");
     assert_eq!(c_mat.get_rows(), 2, "Matrix C row dimension not as expected");
     assert_eq!(c_mat.get_cols(), 2, "Matrix C col dimension not as expected");
+
+    let (d_mat, d_handle) = calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to mulitply Matrix A and Matrix B");
+    calc.free(d_handle)
+        .expect("Failed to free Matrix D");
+
+    let a_handle: MatrixHandle = calc.overwrite(a_handle, d_mat)
+        .expect("Failed to overwrite Matrix A");
+
+    let e_handle: MatrixHandle = calc.mat_mul_resident(b_handle, a_handle)
+        .expect("Failed to mulitply Matrix B and Matrix A");
+    let f_handle: MatrixHandle = calc.mat_mul_resident(e_handle, a_handle)
+        .expect("Failed to mulitply Matrix E and Matrix A");
+
+    let f_mat: Matrix = calc.fetch(f_handle)
+        .expect("Failed to fetch Matrix F");
+
+    assert_eq!(f_mat.get_data(), vec![1998.0, 1665.0, 4218.0, 3515.0, 1998.0, 1665.0], "Matrix F data not as expected");
+
+    use oscirs_linalg::expr::Expr;
+
+    let expr: Expr = b_handle * a_handle + e_handle * 2.0;
+    let g_mat: Matrix = calc.eval(&expr)
+        .expect("Failed to evaluate expression");
+
+    assert_eq!(g_mat.get_data(), vec![162.0, 135.0, 342.0, 285.0, 162.0, 135.0], "Matrix G data not as expected");
 }
 
 #[test]
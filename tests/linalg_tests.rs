@@ -1,12 +1,40 @@
 use oscirs_linalg::Result;
 use oscirs_linalg::err::LAError;
+use oscirs_linalg::complex::Complex;
+use oscirs_linalg::sparse::csr::CsrMatrix;
+use oscirs_linalg::sparse::csc::CscMatrix;
+use oscirs_linalg::iterative::{
+    SolverOptions,
+    ConvergenceReport
+};
+use oscirs_linalg::iterative::krylov;
+use oscirs_linalg::iterative::precond::{
+    Jacobi,
+    Ilu0
+};
 use oscirs_linalg::matrix::Matrix;
+use oscirs_linalg::view::MatrixView;
+use oscirs_linalg::elementwise::Axis;
+use oscirs_linalg::expr::Expr;
+use oscirs_linalg::kernel::{
+    KernelArg,
+    KernelBuilder,
+    CustomKernel,
+    ShapeCheck,
+    ShapeRule,
+    WorkSizeRule
+};
 use oscirs_linalg::calculator::{
+    BackendKind,
     Calculator,
+    DeviceInfo,
+    DeviceType,
+    MatrixHandle,
     ParameterFunction
 };
 
 #[test]
+#[allow(deprecated)]
 fn matrix_ewmult_custom() {
     let mut calc: Calculator = Calculator::init()
         .expect("Failed to initialize calculator");
@@ -50,6 +78,15 @@ fn matrix_ewmult_custom() {
         }
     );
 
+    // OpenCL kernels cannot run on the CPU fallback backend
+    if calc.get_backend_kind() == BackendKind::Cpu {
+        let load_result: Result<usize> = unsafe {
+            calc.load_custom_fn(new_program, new_kernel_name, custom_param_function)
+        };
+        assert!(matches!(load_result, Err(LAError::UnsupportedError)), "Custom kernel not rejected on CPU backend");
+        return;
+    }
+
     let custom_idx: usize = unsafe {
         calc.load_custom_fn(new_program, new_kernel_name, custom_param_function)
             .expect("Failed to load custom function")
@@ -65,9 +102,9 @@ fn matrix_ewmult_custom() {
     let b_mat: Matrix = Matrix::new(b_vec, 2, 3)
         .expect("Failed to create Matrix B");
 
-    let a_idx: usize = calc.store_matrix(a_mat)
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat)
         .expect("Failed to store Matrix A in calculator memory");
-    let b_idx: usize = calc.store_matrix(b_mat)
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat)
         .expect("Failed to store Matrix B in calculator memory");
 
     let (c_mat, _) = unsafe {
@@ -75,7 +112,7 @@ fn matrix_ewmult_custom() {
             custom_idx,
             None,
             Some(vec![3 as i32]),
-            vec![a_idx, b_idx],
+            vec![a_handle, b_handle],
         ).expect("Failed to multiply Matrix A and Matrix B elementwise")
     };
 
@@ -84,6 +121,115 @@ fn matrix_ewmult_custom() {
     assert_eq!(c_mat.get_cols(), 3, "Matrix C row dimension not as expected");
 }
 
+#[test]
+fn custom_kernel_builder() {
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    // Scalars and matrices interleaved, bound in declared order
+    let new_program: &str = r#"
+    kernel void scaled_ewmult (
+        global float *c,
+        const float s,
+        const global float* a,
+        const int N,
+        const global float* b
+    ) {
+        const int globalRow = get_global_id(0);
+        const int globalCol = get_global_id(1);
+
+        c[globalRow * N + globalCol] = s * a[globalRow * N + globalCol] * b[globalRow * N + globalCol];
+    }
+    "#;
+
+    let builder: KernelBuilder = KernelBuilder::new(new_program, "scaled_ewmult")
+        .float_arg()
+        .matrix_arg()
+        .int_arg()
+        .matrix_arg()
+        .check_shapes(ShapeCheck::SameShape(0, 1))
+        .output_shape(ShapeRule::SameAs(0))
+        .work_sizes(WorkSizeRule::Grid);
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0], 2, 3)
+        .expect("Failed to create Matrix B");
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone())
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone())
+        .expect("Failed to store Matrix B in calculator memory");
+    let d_handle: MatrixHandle = calc.store_matrix(b_mat.transpose())
+        .expect("Failed to store Matrix D in calculator memory");
+
+    let args: [KernelArg; 4] = [KernelArg::Float(2.0), a_handle.into(), KernelArg::Int(3), b_handle.into()];
+
+    let (output_rows, output_cols, work_sizes) = builder.launch_params(&args)
+        .expect("Failed to compute launch parameters");
+    assert_eq!((output_rows, output_cols), (2, 3), "Output shape not as expected");
+    assert_eq!(work_sizes, vec![2, 3], "Work sizes not as expected");
+
+    assert!(matches!(builder.launch_params(&args[..3]), Err(LAError::ArgumentError)), "Missing argument not rejected");
+    assert!(
+        matches!(builder.launch_params(&[KernelArg::Int(2), a_handle.into(), KernelArg::Int(3), b_handle.into()]), Err(LAError::ArgumentError)),
+        "Mistyped argument not rejected"
+    );
+    assert!(
+        matches!(builder.launch_params(&[KernelArg::Float(2.0), a_handle.into(), KernelArg::Int(3), d_handle.into()]), Err(LAError::SizeError)),
+        "Mismatched shapes not rejected"
+    );
+
+    let bad_rule: Result<()> = KernelBuilder::new(new_program, "scaled_ewmult")
+        .matrix_arg()
+        .output_shape(ShapeRule::Product(0, 1))
+        .validate();
+    assert!(matches!(bad_rule, Err(LAError::IndexError)), "Rule on undeclared matrix not rejected");
+
+    let fixed_builder: KernelBuilder = builder.clone().work_sizes(WorkSizeRule::Fixed(vec![3, 2]));
+    assert!(fixed_builder.launch_params(&args).is_ok(), "Fixed work sizes within output not accepted");
+    let oversized_builder: KernelBuilder = builder.clone().work_sizes(WorkSizeRule::Fixed(vec![4, 2]));
+    assert!(matches!(oversized_builder.launch_params(&args), Err(LAError::SizeError)), "Oversized fixed work sizes not rejected");
+
+    // Type names as reported by a device, with None where argument info is unavailable
+    let kernel_arg_types: Vec<Option<String>> = vec![
+        Some("float*".to_string()),
+        Some("float".to_string()),
+        Some("float*".to_string()),
+        None,
+        Some("float*".to_string())
+    ];
+    builder.check_kernel_args(&kernel_arg_types)
+        .expect("Failed to match declared signature with kernel arguments");
+    assert!(matches!(builder.check_kernel_args(&kernel_arg_types[..4]), Err(LAError::ArgumentError)), "Wrong kernel arg count not rejected");
+    let mut mistyped_arg_types: Vec<Option<String>> = kernel_arg_types.clone();
+    mistyped_arg_types[1] = Some("int".to_string());
+    assert!(matches!(builder.check_kernel_args(&mistyped_arg_types), Err(LAError::ArgumentError)), "Wrong kernel arg type not rejected");
+
+    // OpenCL kernels cannot run on the CPU fallback backend
+    if calc.get_backend_kind() == BackendKind::Cpu {
+        assert!(matches!(calc.load_kernel(builder), Err(LAError::UnsupportedError)), "Custom kernel not rejected on CPU backend");
+        return;
+    }
+
+    let short_builder: KernelBuilder = KernelBuilder::new(new_program, "scaled_ewmult")
+        .float_arg()
+        .matrix_arg()
+        .int_arg();
+    assert!(matches!(calc.load_kernel(short_builder), Err(LAError::ArgumentError)), "Wrong kernel arg count not rejected on load");
+
+    let kernel: CustomKernel = calc.load_kernel(builder)
+        .expect("Failed to load custom kernel");
+
+    let (c_mat, _) = calc.run_kernel(&kernel, &args)
+        .expect("Failed to run custom kernel");
+    assert_eq!(c_mat.get_data(), vec![4.0, 4.0, 12.0, 24.0, 20.0, 12.0], "Matrix C data not as expected");
+
+    calc.free(b_handle)
+        .expect("Failed to free Matrix B");
+    assert!(matches!(calc.run_kernel(&kernel, &args), Err(LAError::StaleHandleError)), "Stale handle not rejected");
+}
+
 #[test]
 fn matrix_multiplication() {
     let mut calc: Calculator = Calculator::init()
@@ -103,33 +249,33 @@ fn matrix_multiplication() {
     let b_mat: Matrix = Matrix::new(b_vec, 3, 2)
         .expect("Failed to create Matrix B");
 
-    let a_idx: usize = calc.store_matrix(a_mat)
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat)
         .expect("Failed to store Matrix A in calculator memory");
-    let b_idx: usize = calc.store_matrix(b_mat)
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat)
         .expect("Failed to store Matrix B in calculator memory");
 
-    let (c_mat, c_idx) = calc.mat_mul(a_idx, b_idx)
+    let (c_mat, c_handle) = calc.mat_mul(a_handle, b_handle)
         .expect("Failed to mulitply Matrix A and Matrix B");
 
     assert_eq!(c_mat.get_data(), c_vec, "Matrix C data not as expected");
     assert_eq!(c_mat.get_rows(), 2, "Matrix C row dimension not as expected");
     assert_eq!(c_mat.get_cols(), 2, "Matrix C col dimension not as expected");
 
-    let (d_mat, _) = calc.mat_mul(c_idx, a_idx)
+    let (d_mat, _) = calc.mat_mul(c_handle, a_handle)
         .expect("Failed to multiply Matrix C and Matrix A");
 
     assert_eq!(d_mat.get_data(), d_vec, "Matrix D data not as expected");
     assert_eq!(d_mat.get_rows(), 2, "Matrix D row dimension not as expected");
     assert_eq!(d_mat.get_cols(), 3, "Matrix D col dimension not as expected");
 
-    let (e_mat, e_idx) = calc.mat_mul(b_idx, a_idx)
+    let (e_mat, e_handle) = calc.mat_mul(b_handle, a_handle)
         .expect("Failed to mulitply Matrix B and Matrix A");
 
     assert_eq!(e_mat.get_data(), e_vec, "Matrix E data not as expected");
     assert_eq!(e_mat.get_rows(), 3, "Matrix E row dimension not as expected");
     assert_eq!(e_mat.get_cols(), 3, "Matrix E col dimension not as expected");
 
-    let (f_mat, _) = calc.mat_mul(e_idx, b_idx)
+    let (f_mat, _) = calc.mat_mul(e_handle, b_handle)
         .expect("Failed to multiply Matrix E and Matrix B");
 
     assert_eq!(f_mat.get_data(), f_vec, "Matrix F data not as expected");
@@ -137,6 +283,389 @@ fn matrix_multiplication() {
     assert_eq!(f_mat.get_cols(), 2, "Matrix F col dimension not as expected");
 }
 
+#[test]
+fn scalar_only_kernel() {
+    // Fresh calculator with nothing stored before the kernel runs
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let new_program: &str = r#"
+    kernel void fill (
+        global float *c,
+        const float s,
+        const int N
+    ) {
+        const int i = get_global_id(0);
+
+        c[i] = s * N + i;
+    }
+    "#;
+
+    let builder: KernelBuilder = KernelBuilder::new(new_program, "fill")
+        .float_arg()
+        .int_arg()
+        .output_shape(ShapeRule::Fixed(2, 3));
+
+    let args: [KernelArg; 2] = [KernelArg::Float(2.0), KernelArg::Int(3)];
+    assert_eq!(builder.launch_params(&args).expect("Failed to compute launch parameters"), (2, 3, vec![6]), "Launch parameters not as expected");
+
+    // OpenCL kernels cannot run on the CPU fallback backend
+    if calc.get_backend_kind() == BackendKind::Cpu {
+        assert!(matches!(calc.load_kernel(builder), Err(LAError::UnsupportedError)), "Custom kernel not rejected on CPU backend");
+        return;
+    }
+
+    let kernel: CustomKernel = calc.load_kernel(builder)
+        .expect("Failed to load custom kernel");
+
+    let (c_mat, _) = calc.run_kernel(&kernel, &args)
+        .expect("Failed to run scalar-only kernel");
+    assert_eq!(c_mat.get_data(), vec![6.0, 7.0, 8.0, 9.0, 10.0, 11.0], "Matrix C data not as expected");
+    assert_eq!((c_mat.get_rows(), c_mat.get_cols()), (2, 3), "Matrix C dimensions not as expected");
+}
+
+#[test]
+fn tiled_matrix_multiplication() {
+    // Dimensions are not multiples of any tile size, so edge tiles are partial
+    let a_vec: Vec<f32> = (0..33 * 17).map(|idx| (idx % 7) as f32 - 3.0).collect();
+    let b_vec: Vec<f32> = (0..17 * 35).map(|idx| (idx % 5) as f32 - 2.0).collect();
+
+    let a_mat: Matrix = Matrix::new(a_vec, 33, 17)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(b_vec, 17, 35)
+        .expect("Failed to create Matrix B");
+
+    let expected: Matrix = (&a_mat * &b_mat)
+        .expect("Failed to multiply Matrix A and Matrix B on the CPU");
+
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+    if calc.get_backend_kind() == BackendKind::OpenCl {
+        assert!(calc.get_mat_mul_tile_size().is_some(), "Tiled mat_mul not selected");
+    }
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone())
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone())
+        .expect("Failed to store Matrix B in calculator memory");
+
+    let (c_mat, _) = calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B");
+    assert_eq!(c_mat.get_data(), expected.get_data(), "Tiled Matrix C data not as expected");
+    assert_eq!((c_mat.get_rows(), c_mat.get_cols()), (33, 35), "Tiled Matrix C dimensions not as expected");
+
+    // No candidate tile fits below the smallest tile size, so the untiled kernel is used
+    let mut naive_calc: Calculator = Calculator::builder()
+        .max_mat_mul_tile_size(4)
+        .build()
+        .expect("Failed to initialize calculator");
+    assert_eq!(naive_calc.get_mat_mul_tile_size(), None, "Untiled mat_mul not selected");
+
+    let a_handle: MatrixHandle = naive_calc.store_matrix(a_mat)
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = naive_calc.store_matrix(b_mat)
+        .expect("Failed to store Matrix B in calculator memory");
+
+    let (d_mat, _) = naive_calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B");
+    assert_eq!(d_mat.get_data(), expected.get_data(), "Untiled Matrix D data not as expected");
+}
+
+#[test]
+fn device_selection() {
+    let devices: Vec<DeviceInfo> = Calculator::list_devices()
+        .expect("Failed to list devices");
+
+    let cpu_calc: Calculator = Calculator::builder()
+        .backend(BackendKind::Cpu)
+        .build()
+        .expect("Failed to build CPU calculator");
+    assert_eq!(cpu_calc.get_backend_kind(), BackendKind::Cpu, "Forced CPU backend not as expected");
+    assert!(cpu_calc.get_device_info().is_none(), "CPU backend reported an OpenCL device");
+
+    let missing_name: &str = "no such opencl device";
+    assert!(matches!(Calculator::builder().device_name(missing_name).build(), Err(LAError::DeviceNotFoundError)), "Unmatched device name not rejected");
+    assert!(matches!(Calculator::builder().platform_index(devices.len() + 100).build(), Err(LAError::DeviceNotFoundError)), "Unmatched platform index not rejected");
+
+    let fallback_calc: Calculator = Calculator::builder()
+        .device_name(missing_name)
+        .cpu_fallback(true)
+        .build()
+        .expect("Failed to fall back to CPU calculator");
+    assert_eq!(fallback_calc.get_backend_kind(), BackendKind::Cpu, "Fallback backend not as expected");
+
+    // Selecting a listed device by platform and index must reach that device
+    if let Some(first) = devices.iter().find(|info| info.device_type == DeviceType::Gpu) {
+        let gpu_calc: Calculator = Calculator::builder()
+            .platform_index(first.platform_index)
+            .device_index(first.device_index)
+            .build()
+            .expect("Failed to build calculator on listed device");
+        assert_eq!(gpu_calc.get_device_info().map(|info| info.name.clone()), Some(first.name.clone()), "Selected device not as expected");
+    }
+}
+
+#[test]
+fn kernel_build_errors() {
+    let build_error: LAError = LAError::KernelBuildError {
+        kernel_name: "broken".to_string(),
+        build_log: "error: use of undeclared identifier 'x'".to_string()
+    };
+    let message: String = build_error.to_string();
+    assert!(message.contains("broken") && message.contains("undeclared identifier"), "Build error message not as expected");
+
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let broken_program: &str = r#"
+    kernel void broken (global float *c) {
+        c[get_global_id(0)] = x;
+    }
+    "#;
+
+    let builder: KernelBuilder = KernelBuilder::new(broken_program, "broken")
+        .options("-cl-fast-relaxed-math")
+        .output_shape(ShapeRule::Fixed(1, 1));
+
+    let load_result: Result<CustomKernel> = calc.load_kernel(builder);
+
+    match calc.get_backend_kind() {
+        BackendKind::Cpu => assert!(matches!(load_result, Err(LAError::UnsupportedError)), "Custom kernel not rejected on CPU backend"),
+        BackendKind::OpenCl => match load_result {
+            Err(LAError::KernelBuildError { kernel_name, .. }) => assert_eq!(kernel_name, "broken", "Failed kernel name not as expected"),
+            _ => panic!("Broken kernel did not return a build error")
+        }
+    }
+}
+
+#[test]
+fn cpu_backend() {
+    let mut calc: Calculator = Calculator::init_cpu();
+    assert_eq!(calc.get_backend_kind(), BackendKind::Cpu, "Backend kind not as expected");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0], 3, 2)
+        .expect("Failed to create Matrix B");
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone())
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone())
+        .expect("Failed to store Matrix B in calculator memory");
+    assert_eq!((a_handle.get_rows(), a_handle.get_cols()), (2, 3), "Stored matrix handle shape not as expected");
+
+    let (c_mat, c_handle) = calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B");
+    assert_eq!((c_handle.get_rows(), c_handle.get_cols()), (2, 2), "Result matrix handle shape not as expected");
+    assert_eq!(c_mat.get_data(), (a_mat * b_mat).unwrap().get_data(), "CPU backend product not as expected");
+
+    let (d_mat, _) = calc.mat_mul(c_handle, a_handle)
+        .expect("Failed to multiply Matrix C and Matrix A");
+    assert_eq!(d_mat.get_data(), vec![52.0, 74.0, 96.0, 130.0, 185.0, 240.0], "Chained CPU backend product not as expected");
+
+    assert!(matches!(calc.mat_mul(a_handle, a_handle), Err(LAError::SizeError)), "Mismatched product not rejected");
+}
+
+#[test]
+fn calculator_memory_management() {
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::identity(2);
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone())
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat)
+        .expect("Failed to store Matrix B in calculator memory");
+
+    let (c_mat, c_handle) = calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B");
+    assert_eq!(c_mat.get_data(), a_mat.get_data(), "Product with identity not as expected");
+
+    calc.free(c_handle).expect("Failed to free Matrix C");
+    assert!(matches!(calc.free(c_handle), Err(LAError::StaleHandleError)), "Double free not rejected");
+    assert!(matches!(calc.mat_mul(c_handle, b_handle), Err(LAError::StaleHandleError)), "Freed handle not rejected");
+
+    // Freed slot is reused, but the old handle still refers to the freed matrix
+    let (_, reused_handle) = calc.mat_mul(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B again");
+    assert_ne!(reused_handle, c_handle, "Reused slot handle not distinct");
+    assert!(matches!(calc.mat_mul(c_handle, b_handle), Err(LAError::StaleHandleError)), "Handle to reused slot not rejected");
+
+    let mut other_calc: Calculator = Calculator::init_cpu();
+    assert!(matches!(other_calc.free(a_handle), Err(LAError::StaleHandleError)), "Foreign handle not rejected");
+
+    // Overwritten matrix is used by subsequent calculations through its new handle
+    let scaled_mat: Matrix = Matrix::new(vec![2.0, 0.0, 0.0, 2.0], 2, 2)
+        .expect("Failed to create scaled Matrix B");
+    let scaled_handle: MatrixHandle = calc.overwrite(b_handle, scaled_mat)
+        .expect("Failed to overwrite Matrix B");
+    assert!(matches!(calc.mat_mul(a_handle, b_handle), Err(LAError::StaleHandleError)), "Overwritten handle not rejected");
+
+    let (d_mat, _) = calc.mat_mul(a_handle, scaled_handle)
+        .expect("Failed to multiply Matrix A and overwritten Matrix B");
+    assert_eq!(d_mat.get_data(), vec![2.0, 4.0, 6.0, 8.0], "Product with overwritten matrix not as expected");
+
+    // Overwriting with a different size replaces the backend storage and the handle shape
+    let column_mat: Matrix = Matrix::new(vec![1.0, 1.0], 2, 1)
+        .expect("Failed to create column Matrix");
+    let column_handle: MatrixHandle = calc.overwrite(scaled_handle, column_mat)
+        .expect("Failed to overwrite Matrix B with column");
+    assert_eq!(column_handle.get_cols(), 1, "Overwritten handle shape not as expected");
+
+    let (e_mat, e_handle) = calc.mat_mul(a_handle, column_handle)
+        .expect("Failed to multiply Matrix A and column Matrix");
+    assert_eq!(e_mat.get_data(), vec![3.0, 7.0], "Product with resized matrix not as expected");
+
+    // Output handles feed straight back into further operations
+    assert!(matches!(calc.mat_mul(e_handle, a_handle), Err(LAError::SizeError)), "Mismatched handle product not rejected");
+
+    calc.clear();
+    assert!(matches!(calc.mat_mul(a_handle, column_handle), Err(LAError::StaleHandleError)), "Cleared handle not rejected");
+    assert!(matches!(calc.overwrite(a_handle, a_mat.clone()), Err(LAError::StaleHandleError)), "Overwrite of cleared handle not rejected");
+
+    let f_handle: MatrixHandle = calc.store_matrix(a_mat)
+        .expect("Failed to store Matrix A after clear");
+    assert_ne!(f_handle, a_handle, "Cleared handle revived");
+}
+
+#[test]
+fn resident_results() {
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0], 3, 2)
+        .expect("Failed to create Matrix B");
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone())
+        .expect("Failed to store Matrix A in calculator memory");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone())
+        .expect("Failed to store Matrix B in calculator memory");
+
+    // Intermediate product never leaves backend memory
+    let c_handle: MatrixHandle = calc.mat_mul_resident(a_handle, b_handle)
+        .expect("Failed to multiply Matrix A and Matrix B on device");
+    let d_handle: MatrixHandle = calc.mat_mul_resident(c_handle, a_handle)
+        .expect("Failed to multiply Matrix C and Matrix A on device");
+    assert_eq!((d_handle.get_rows(), d_handle.get_cols()), (2, 3), "Resident result shape not as expected");
+
+    let expected_mat: Matrix = ((&a_mat * &b_mat).unwrap() * &a_mat).unwrap();
+    let d_mat: Matrix = calc.fetch(d_handle)
+        .expect("Failed to fetch Matrix D");
+    assert_eq!(d_mat.get_data(), expected_mat.get_data(), "Fetched chained product not as expected");
+    assert_eq!(calc.fetch(d_handle).expect("Failed to fetch Matrix D again").get_data(), d_mat.get_data(), "Repeated fetch not as expected");
+    assert_eq!(calc.fetch(a_handle).expect("Failed to fetch Matrix A").get_data(), a_mat.get_data(), "Fetched input not as expected");
+
+    calc.free(c_handle).expect("Failed to free unfetched Matrix C");
+    assert!(matches!(calc.fetch(c_handle), Err(LAError::StaleHandleError)), "Fetch of freed handle not rejected");
+}
+
+#[test]
+fn lazy_expressions() {
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0], 3, 2)
+        .expect("Failed to create Matrix B");
+    let c_mat: Matrix = Matrix::new(vec![1.0, 0.0, 2.0, 1.0], 2, 2)
+        .expect("Failed to create Matrix C");
+    let d_mat: Matrix = Matrix::new(vec![3.0, 1.0, 0.0, 2.0], 2, 2)
+        .expect("Failed to create Matrix D");
+    let e_mat: Matrix = Matrix::new(vec![1.0, 1.0, 1.0, 1.0], 2, 2)
+        .expect("Failed to create Matrix E");
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone()).expect("Failed to store Matrix A");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone()).expect("Failed to store Matrix B");
+    let c_handle: MatrixHandle = calc.store_matrix(c_mat.clone()).expect("Failed to store Matrix C");
+    let d_handle: MatrixHandle = calc.store_matrix(d_mat.clone()).expect("Failed to store Matrix D");
+    let e_handle: MatrixHandle = calc.store_matrix(e_mat.clone()).expect("Failed to store Matrix E");
+
+    // A*B + C*D - E recorded lazily and evaluated with a single readback
+    let expr: Expr = a_handle * b_handle + c_handle * d_handle - e_handle;
+    assert_eq!(expr.shape().expect("Failed to check expression shape"), (2, 2), "Expression shape not as expected");
+
+    let ab_mat: Matrix = (&a_mat * &b_mat).unwrap();
+    let cd_mat: Matrix = (&c_mat * &d_mat).unwrap();
+    let expected_mat: Matrix = ((ab_mat + cd_mat).unwrap() - &e_mat).unwrap();
+
+    let output_mat: Matrix = calc.eval(&expr).expect("Failed to evaluate expression");
+    assert!(output_mat.approx_eq(&expected_mat, 1e-6, 1e-6), "Evaluated expression not as expected");
+
+    // Scaling and negation fuse with the surrounding elementwise operations
+    let scaled_handle: MatrixHandle = calc.eval_resident(&(-(c_handle * 2.0) + Expr::input(e_handle) * 0.5))
+        .expect("Failed to evaluate scaled expression");
+    assert_eq!(calc.fetch(scaled_handle).expect("Failed to fetch scaled result").get_data(), vec![-1.5, 0.5, -3.5, -1.5], "Scaled expression not as expected");
+
+    // Results feed back into further expressions
+    let chained_mat: Matrix = calc.eval(&(scaled_handle * e_handle)).expect("Failed to evaluate chained expression");
+    assert_eq!(chained_mat.get_data(), vec![-1.0, -1.0, -5.0, -5.0], "Chained expression not as expected");
+
+    // Mismatched shapes are rejected before anything runs
+    let mismatched: Expr = a_handle + b_handle;
+    assert!(matches!(mismatched.shape(), Err(LAError::SizeError)), "Mismatched expression shape not rejected");
+    assert!(matches!(calc.eval(&mismatched), Err(LAError::SizeError)), "Mismatched expression not rejected");
+
+    calc.free(e_handle).expect("Failed to free Matrix E");
+    assert!(matches!(calc.eval(&expr), Err(LAError::StaleHandleError)), "Expression with freed input not rejected");
+}
+
+#[test]
+fn builtin_kernels() {
+    let mut calc: Calculator = Calculator::init()
+        .expect("Failed to initialize calculator");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![0.5, 1.0, 1.5, 2.0, 2.5, 3.0], 2, 3)
+        .expect("Failed to create Matrix B");
+
+    let a_handle: MatrixHandle = calc.store_matrix(a_mat.clone()).expect("Failed to store Matrix A");
+    let b_handle: MatrixHandle = calc.store_matrix(b_mat.clone()).expect("Failed to store Matrix B");
+
+    let sum_handle: MatrixHandle = calc.add(a_handle, b_handle).expect("Failed to add matrices");
+    let diff_handle: MatrixHandle = calc.sub(a_handle, b_handle).expect("Failed to subtract matrices");
+    let prod_handle: MatrixHandle = calc.hadamard(a_handle, b_handle).expect("Failed to multiply matrices elementwise");
+    assert!(calc.fetch(sum_handle).unwrap().approx_eq(&(&a_mat + &b_mat).unwrap(), 1e-6, 1e-6), "Sum not as expected");
+    assert!(calc.fetch(diff_handle).unwrap().approx_eq(&(&a_mat - &b_mat).unwrap(), 1e-6, 1e-6), "Difference not as expected");
+    assert!(calc.fetch(prod_handle).unwrap().approx_eq(&a_mat.hadamard(&b_mat).unwrap(), 1e-6, 1e-6), "Hadamard product not as expected");
+
+    let shifted_handle: MatrixHandle = calc.add_scalar(a_handle, 1.5).expect("Failed to add scalar");
+    let lowered_handle: MatrixHandle = calc.sub_scalar(a_handle, 1.5).expect("Failed to subtract scalar");
+    let scaled_handle: MatrixHandle = calc.mul_scalar(a_handle, -2.0).expect("Failed to multiply by scalar");
+    assert!(calc.fetch(shifted_handle).unwrap().approx_eq(&(&a_mat + 1.5), 1e-6, 1e-6), "Scalar sum not as expected");
+    assert!(calc.fetch(lowered_handle).unwrap().approx_eq(&(&a_mat - 1.5), 1e-6, 1e-6), "Scalar difference not as expected");
+    assert!(calc.fetch(scaled_handle).unwrap().approx_eq(&(&a_mat * -2.0), 1e-6, 1e-6), "Scalar product not as expected");
+
+    let transpose_handle: MatrixHandle = calc.transpose(a_handle).expect("Failed to transpose Matrix A");
+    assert_eq!((transpose_handle.get_rows(), transpose_handle.get_cols()), (3, 2), "Transpose shape not as expected");
+    assert_eq!(calc.fetch(transpose_handle).unwrap().get_data(), a_mat.transpose().get_data(), "Transpose not as expected");
+
+    let row_sums: MatrixHandle = calc.sum(a_handle, Axis::Row).expect("Failed to sum rows");
+    let col_sums: MatrixHandle = calc.sum(a_handle, Axis::Col).expect("Failed to sum cols");
+    assert_eq!(calc.fetch(row_sums).unwrap().get_data(), vec![6.0, 15.0], "Row sums not as expected");
+    assert_eq!(calc.fetch(col_sums).unwrap().get_data(), vec![5.0, 7.0, 9.0], "Col sums not as expected");
+
+    let exp_handle: MatrixHandle = calc.exp(b_handle).expect("Failed to apply exp");
+    let ln_handle: MatrixHandle = calc.ln(b_handle).expect("Failed to apply ln");
+    let sqrt_handle: MatrixHandle = calc.sqrt(b_handle).expect("Failed to apply sqrt");
+    let tanh_handle: MatrixHandle = calc.tanh(b_handle).expect("Failed to apply tanh");
+    assert!(calc.fetch(exp_handle).unwrap().approx_eq(&b_mat.map(f32::exp), 1e-5, 1e-6), "Exp not as expected");
+    assert!(calc.fetch(ln_handle).unwrap().approx_eq(&b_mat.map(f32::ln), 1e-5, 1e-6), "Ln not as expected");
+    assert!(calc.fetch(sqrt_handle).unwrap().approx_eq(&b_mat.map(f32::sqrt), 1e-5, 1e-6), "Sqrt not as expected");
+    assert!(calc.fetch(tanh_handle).unwrap().approx_eq(&b_mat.map(f32::tanh), 1e-5, 1e-6), "Tanh not as expected");
+
+    // Results chain without readback and shapes are checked against the handles
+    let chained_handle: MatrixHandle = calc.mat_mul_resident(transpose_handle, row_sums).expect("Failed to multiply transpose by row sums");
+    assert_eq!(calc.fetch(chained_handle).unwrap().get_data(), vec![66.0, 87.0, 108.0], "Chained result not as expected");
+    assert!(matches!(calc.add(a_handle, transpose_handle), Err(LAError::SizeError)), "Mismatched sum not rejected");
+}
+
 #[test]
 fn matrix_indexing() {
     let a_vec: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
@@ -147,6 +676,87 @@ fn matrix_indexing() {
     assert_eq!(a_mat[[1, 1]], 5.0, "Indexed value not as expected");
     assert_eq!(a_mat.row(1).expect("Failed to index row"), vec![4.0, 5.0, 6.0], "Indexed row not as expected");
     assert_eq!(a_mat.col(2).expect("Failed to index col"), vec![3.0, 6.0], "Indexed col not as expected");
+    assert_eq!(a_mat.get(0, 2).expect("Failed to get value"), 3.0, "Checked value not as expected");
+    assert!(matches!(a_mat.get(0, 3), Err(LAError::IndexError)), "Out of bounds column not rejected");
+}
+
+#[test]
+fn matrix_views() {
+    let mut a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3)
+        .expect("Failed to create Matrix A");
+
+    a_mat[[0, 0]] = 10.0;
+    *a_mat.get_mut(2, 2).expect("Failed to get mutable value") = 90.0;
+    assert_eq!(a_mat.get_data(), vec![10.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 90.0], "Mutable indexing not as expected");
+
+    let col_view: MatrixView = a_mat.col_view(1).expect("Failed to view col");
+    assert_eq!(col_view.get_rows(), 3, "Column view row dimension not as expected");
+    assert_eq!(col_view[[2, 0]], 8.0, "Column view value not as expected");
+    assert_eq!(col_view.transpose().to_matrix().get_data(), a_mat.col(1).unwrap(), "Transposed column view not as expected");
+
+    let block_view: MatrixView = a_mat.view(1, 1, 2, 2).expect("Failed to view block");
+    assert_eq!(block_view.to_matrix().get_data(), vec![5.0, 6.0, 8.0, 90.0], "Block view not as expected");
+
+    let b_mat: Matrix = Matrix::new(vec![1.0, 1.0, 1.0, 1.0], 2, 2)
+        .expect("Failed to create Matrix B");
+    let sum_mat: Matrix = (block_view + &b_mat).expect("Failed to add view and Matrix B");
+    assert_eq!(sum_mat.get_data(), vec![6.0, 7.0, 9.0, 91.0], "View-Matrix addition not as expected");
+    let prod_mat: Matrix = (a_mat.row_view(0).unwrap() * a_mat.col_view(0).unwrap()).expect("Failed to multiply views");
+    assert_eq!(prod_mat.get_data(), vec![100.0 + 8.0 + 21.0], "View-View multiplication not as expected");
+
+    {
+        let mut row_view = a_mat.row_view_mut(0).expect("Failed to mutably view row");
+        row_view *= 2.0;
+        row_view[[0, 2]] = 0.0;
+    }
+    {
+        let mut block_view = a_mat.view_mut(1, 0, 2, 2).expect("Failed to mutably view block");
+        block_view -= &b_mat;
+    }
+    a_mat.col_view_mut(2).expect("Failed to mutably view col").fill(-1.0);
+    assert_eq!(a_mat.get_data(), vec![20.0, 4.0, -1.0, 3.0, 4.0, -1.0, 6.0, 7.0, -1.0], "Mutable views not as expected");
+
+    assert!(matches!(a_mat.view(2, 2, 2, 1), Err(LAError::IndexError)), "Out of bounds block not rejected");
+}
+
+#[test]
+fn matrix_constructors() {
+    assert_eq!(Matrix::<f32>::zeros(2, 3).get_data(), vec![0.0; 6], "Zeros not as expected");
+    assert_eq!(Matrix::<f32>::ones(3, 1).get_data(), vec![1.0; 3], "Ones not as expected");
+    assert_eq!(Matrix::<f32>::identity(2).get_data(), vec![1.0, 0.0, 0.0, 1.0], "Identity not as expected");
+    assert_eq!(Matrix::diag(&[2.0, 3.0]).get_data(), vec![2.0, 0.0, 0.0, 3.0], "Diagonal matrix not as expected");
+
+    let fn_mat: Matrix = Matrix::from_fn(2, 3, |row, col| (row * 3 + col) as f32);
+    assert_eq!(fn_mat.get_data(), vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], "Matrix from function not as expected");
+
+    let rows_mat: Matrix = Matrix::from_rows(&[vec![0.0, 1.0, 2.0], vec![3.0, 4.0, 5.0]])
+        .expect("Failed to create Matrix from rows");
+    assert_eq!(rows_mat.get_data(), fn_mat.get_data(), "Matrix from rows not as expected");
+    let cols_mat: Matrix = Matrix::from_cols(&[[0.0, 3.0], [1.0, 4.0], [2.0, 5.0]])
+        .expect("Failed to create Matrix from cols");
+    assert_eq!(cols_mat.get_data(), fn_mat.get_data(), "Matrix from cols not as expected");
+    assert!(matches!(Matrix::from_rows(&[vec![1.0], vec![1.0, 2.0]]), Err(LAError::SizeError)), "Ragged rows not rejected");
+
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![5.0, 6.0], 2, 1)
+        .expect("Failed to create Matrix B");
+
+    let h_mat: Matrix = Matrix::hstack(&[&a_mat, &b_mat]).expect("Failed to stack horizontally");
+    assert_eq!(h_mat.get_data(), vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0], "Horizontal stack not as expected");
+    let v_mat: Matrix = Matrix::vstack(&[&a_mat, &b_mat.transpose()]).expect("Failed to stack vertically");
+    assert_eq!(v_mat.get_data(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], "Vertical stack not as expected");
+    assert!(matches!(Matrix::vstack(&[&a_mat, &b_mat]), Err(LAError::SizeError)), "Mismatched vertical stack not rejected");
+
+    let c_mat: Matrix = Matrix::new(vec![7.0], 1, 1)
+        .expect("Failed to create Matrix C");
+    let block_mat: Matrix = Matrix::block(&[&[&a_mat, &b_mat], &[&b_mat.transpose(), &c_mat]])
+        .expect("Failed to assemble block matrix");
+    assert_eq!(block_mat.get_data(), vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0, 5.0, 6.0, 7.0], "Block matrix not as expected");
+
+    let kron_mat: Matrix = Matrix::<f32>::identity(2).kron(&b_mat);
+    assert_eq!(kron_mat.get_data(), vec![5.0, 0.0, 6.0, 0.0, 0.0, 5.0, 0.0, 6.0], "Kronecker product not as expected");
+    assert_eq!(kron_mat.get_rows(), 4, "Kronecker product row dimension not as expected");
 }
 
 #[test]
@@ -196,4 +806,568 @@ fn cpu_operations() {
     assert_eq!(mul_mat.get_data(), mul_vec, "Matrix-Matrix multiplication data not as expected");
     assert_eq!(mul_mat.get_rows(), 2, "Matrix-Matrix multiplication row dimension not as expected");
     assert_eq!(mul_mat.get_cols(), 2, "Matrix-Matrix multiplication col dimension not as expected");
-}
\ No newline at end of file
+}
+
+#[test]
+fn blocked_multiplication() {
+    // Dimensions straddle the internal block sizes
+    let a_mat: Matrix<f64> = Matrix::from_fn(70, 300, |row, col| ((row * 7 + col * 3) % 11) as f64 - 5.0);
+    let b_mat: Matrix<f64> = Matrix::from_fn(300, 260, |row, col| ((row * 5 + col * 2) % 13) as f64 - 6.0);
+
+    let expected_mat: Matrix<f64> = Matrix::from_fn(70, 260, |row, col| {
+        (0..300).map(|k| a_mat[[row, k]] * b_mat[[k, col]]).sum()
+    });
+
+    let product_mat: Matrix<f64> = (&a_mat * &b_mat).expect("Failed to multiply Matrix A by Matrix B");
+    assert_eq!(product_mat.get_data(), expected_mat.get_data(), "Blocked multiplication not as expected");
+
+    let empty_mat: Matrix<f64> = (Matrix::zeros(3, 0) * Matrix::zeros(0, 2)).expect("Failed to multiply empty matrices");
+    assert_eq!(empty_mat.get_data(), vec![0.0; 6], "Empty inner dimension product not as expected");
+}
+
+#[test]
+fn borrowed_operations() {
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0], 2, 3)
+        .expect("Failed to create Matrix B");
+
+    assert_eq!((&a_mat + 2.0).get_data(), vec![3.0, 4.0, 5.0, 6.0, 7.0, 8.0], "Borrowed Matrix-Float addition not as expected");
+    assert_eq!((2.0 - &a_mat).get_data(), vec![1.0, 0.0, -1.0, -2.0, -3.0, -4.0], "Float-Borrowed Matrix subtraction not as expected");
+    assert_eq!((-&a_mat).get_data(), vec![-1.0, -2.0, -3.0, -4.0, -5.0, -6.0], "Borrowed Matrix negation not as expected");
+
+    let add_vec: Vec<f32> = vec![3.0, 3.0, 5.0, 7.0, 7.0, 7.0];
+    assert_eq!((&a_mat + &b_mat).expect("Failed to add borrowed matrices").get_data(), add_vec, "Borrowed addition not as expected");
+    assert_eq!((a_mat.clone() + &b_mat).expect("Failed to add borrowed Matrix B").get_data(), add_vec, "Owned-Borrowed addition not as expected");
+
+    let sub_vec: Vec<f32> = vec![-1.0, 1.0, 1.0, 1.0, 3.0, 5.0];
+    assert_eq!((&a_mat - b_mat.clone()).expect("Failed to subtract Matrix B").get_data(), sub_vec, "Borrowed-Owned subtraction not as expected");
+    assert_eq!((&a_mat - &b_mat).expect("Failed to subtract borrowed matrices").get_data(), sub_vec, "Borrowed subtraction not as expected");
+
+    let b_transpose: Matrix = b_mat.transpose();
+    let mul_vec: Vec<f32> = vec![10.0, 10.0, 25.0, 28.0];
+    assert_eq!((&a_mat * &b_transpose).expect("Failed to multiply borrowed matrices").get_data(), mul_vec, "Borrowed multiplication not as expected");
+
+    let mut c_mat: Matrix = a_mat.clone();
+    c_mat += &b_mat;
+    c_mat -= 1.0;
+    c_mat *= 2.0;
+    assert_eq!(c_mat.get_data(), vec![4.0, 4.0, 8.0, 12.0, 12.0, 12.0], "In-place scalar and matrix ops not as expected");
+
+    c_mat -= b_mat;
+    c_mat *= &b_transpose;
+    assert_eq!(c_mat.get_data(), vec![19.0, 18.0, 50.0, 58.0], "In-place matrix multiplication not as expected");
+    assert_eq!(c_mat.get_cols(), 2, "In-place multiplication col dimension not as expected");
+
+    assert!(matches!(&a_mat + &b_transpose, Err(LAError::SizeError)), "Mismatched borrowed addition not rejected");
+}
+
+#[test]
+fn elementwise_operations() {
+    let a_mat: Matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(vec![2.0, 1.0, 2.0, 4.0, 2.0, 1.0], 2, 3)
+        .expect("Failed to create Matrix B");
+
+    assert_eq!(a_mat.hadamard(&b_mat).expect("Failed to compute Hadamard product").get_data(), vec![2.0, 2.0, 6.0, 16.0, 10.0, 6.0], "Hadamard product not as expected");
+    assert_eq!(a_mat.div_elem(&b_mat).expect("Failed to divide elementwise").get_data(), vec![0.5, 2.0, 1.5, 1.0, 2.5, 6.0], "Elementwise division not as expected");
+    assert_eq!(a_mat.map(|value| value * value).get_data(), vec![1.0, 4.0, 9.0, 16.0, 25.0, 36.0], "Map not as expected");
+    assert_eq!(a_mat.map(|value| value as i32 % 2).get_data(), vec![1, 0, 1, 0, 1, 0], "Type-changing map not as expected");
+    assert_eq!(a_mat.zip_map(&b_mat, f32::max).expect("Failed to zip matrices").get_data(), vec![2.0, 2.0, 3.0, 4.0, 5.0, 6.0], "Zip map not as expected");
+    assert!(matches!(a_mat.hadamard(&b_mat.transpose()), Err(LAError::SizeError)), "Mismatched Hadamard product not rejected");
+
+    let col_sums: Matrix = a_mat.sum(Axis::Col);
+    assert_eq!(col_sums.get_data(), vec![5.0, 7.0, 9.0], "Column sums not as expected");
+    assert_eq!(col_sums.get_rows(), 1, "Column sums row dimension not as expected");
+    let row_means: Matrix = a_mat.mean(Axis::Row)
+        .expect("Failed to get row means");
+    assert_eq!(row_means.get_data(), vec![2.0, 5.0], "Row means not as expected");
+    assert_eq!(row_means.get_cols(), 1, "Row means col dimension not as expected");
+
+    assert_eq!(b_mat.min(Axis::Row).expect("Failed to get row minimums").get_data(), vec![1.0, 1.0], "Row minimums not as expected");
+    assert_eq!(b_mat.max(Axis::Col).expect("Failed to get column maximums").get_data(), vec![4.0, 2.0, 2.0], "Column maximums not as expected");
+    assert_eq!(b_mat.argmax(Axis::Row).expect("Failed to get row argmax"), vec![0, 0], "Row argmax not as expected");
+    assert_eq!(b_mat.argmax(Axis::Col).expect("Failed to get column argmax"), vec![1, 1, 0], "Column argmax not as expected");
+    assert_eq!(Matrix::new(vec![3.0, 4.0], 1, 2).unwrap().norm(Axis::Row).get_data(), vec![5.0], "Row norms not as expected");
+    assert!(matches!(Matrix::<f32>::zeros(0, 3).max(Axis::Col), Err(LAError::SizeError)), "Empty reduction not rejected");
+    assert!(matches!(Matrix::<f32>::zeros(2, 0).mean(Axis::Row), Err(LAError::SizeError)), "Empty mean not rejected");
+
+    let col_means: Matrix = a_mat.mean(Axis::Col)
+        .expect("Failed to get column means");
+    let centered: Matrix = a_mat.broadcast(&col_means, |value, mean| value - mean)
+        .expect("Failed to broadcast column means");
+    assert_eq!(centered.get_data(), vec![-1.5, -1.5, -1.5, 1.5, 1.5, 1.5], "Row broadcasting not as expected");
+    let scaled: Matrix = a_mat.broadcast(&Matrix::new(vec![1.0, 10.0], 2, 1).unwrap(), |value, factor| value * factor)
+        .expect("Failed to broadcast column vector");
+    assert_eq!(scaled.get_data(), vec![1.0, 2.0, 3.0, 40.0, 50.0, 60.0], "Column broadcasting not as expected");
+    assert!(matches!(a_mat.broadcast(&row_means.transpose(), |value, mean| value - mean), Err(LAError::SizeError)), "Mismatched broadcast not rejected");
+}
+
+#[test]
+fn norms_and_trace() {
+    let a_mat: Matrix<f64> = Matrix::new(vec![1.0, -2.0, 3.0, -4.0], 2, 2)
+        .expect("Failed to create Matrix A");
+
+    assert!((a_mat.norm_fro() - 30.0_f64.sqrt()).abs() < 1e-12, "Frobenius norm not as expected");
+    assert_eq!(a_mat.norm_1(), 6.0, "1-norm not as expected");
+    assert_eq!(a_mat.norm_inf(), 7.0, "Infinity norm not as expected");
+    assert_eq!(a_mat.trace().expect("Failed to compute trace"), -3.0, "Trace not as expected");
+
+    // Largest singular value is the square root of the largest eigenvalue of A^T * A
+    let ata: Matrix<f64> = (a_mat.transpose() * &a_mat).expect("Failed to compute A^T * A");
+    let sigma_max: f64 = ((ata.trace().unwrap() + (ata.trace().unwrap().powi(2) - 4.0 * 4.0).sqrt()) / 2.0).sqrt();
+    assert!((a_mat.norm_2().expect("Failed to compute spectral norm") - sigma_max).abs() < 1e-10, "Spectral norm not as expected");
+
+    let x_vec: Matrix<f64> = Matrix::new(vec![3.0, -4.0], 2, 1)
+        .expect("Failed to create vector x");
+    assert_eq!(x_vec.norm_fro(), 5.0, "Vector 2-norm not as expected");
+    assert_eq!(x_vec.norm_1(), 7.0, "Vector 1-norm not as expected");
+    assert_eq!(x_vec.norm_inf(), 4.0, "Vector max norm not as expected");
+    assert_eq!(x_vec.inner(&x_vec).expect("Failed to compute inner product"), 25.0, "Inner product not as expected");
+
+    let c_mat: Matrix<Complex> = Matrix::new(vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)], 2, 1)
+        .expect("Failed to create complex vector");
+    assert_eq!(c_mat.inner(&c_mat).expect("Failed to compute complex inner product"), Complex::new(6.0, 0.0), "Complex inner product not as expected");
+
+    let b_mat: Matrix<f64> = a_mat.clone() + 1e-9;
+    assert!(a_mat.approx_eq(&b_mat, 0.0, 1e-8), "Absolute tolerance comparison not as expected");
+    assert!(a_mat.approx_eq(&b_mat, 1e-8, 0.0), "Relative tolerance comparison not as expected");
+    assert!(!a_mat.approx_eq(&b_mat, 1e-12, 1e-12), "Tight tolerance comparison not as expected");
+    assert!(!a_mat.approx_eq(&a_mat.kron(&x_vec), 1.0, 1.0), "Mismatched dimensions compared as equal");
+    assert!(matches!(x_vec.trace(), Err(LAError::SizeError)), "Non-square trace not rejected");
+}
+
+#[test]
+fn lu_decomposition() {
+    let a_vec: Vec<f32> = vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0];
+    let b_vec: Vec<f32> = vec![5.0, -2.0, 9.0];
+
+    let a_mat: Matrix = Matrix::new(a_vec, 3, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(b_vec, 3, 1)
+        .expect("Failed to create Matrix B");
+
+    let lu = a_mat.lu()
+        .expect("Failed to factorize Matrix A");
+
+    let pa_mat: Matrix = (lu.get_p() * a_mat.clone()).expect("Failed to multiply P and A");
+    let lu_mat: Matrix = (lu.get_l() * lu.get_u()).expect("Failed to multiply L and U");
+    for (pa, lu) in pa_mat.get_data().iter().zip(lu_mat.get_data()) {
+        assert!((pa - lu).abs() < 1e-5, "LU factors do not reproduce PA");
+    }
+
+    let x_vec: Vec<f32> = vec![1.0, 1.0, 2.0];
+    let x_mat: Matrix = a_mat.solve(&b_mat)
+        .expect("Failed to solve A * x = b");
+    for (x, expected) in x_mat.get_data().iter().zip(x_vec) {
+        assert!((x - expected).abs() < 1e-5, "Solution not as expected");
+    }
+
+    let det: f32 = a_mat.determinant()
+        .expect("Failed to get determinant of Matrix A");
+    assert!((det + 16.0).abs() < 1e-4, "Determinant not as expected");
+
+    let inv_mat: Matrix = a_mat.inverse()
+        .expect("Failed to invert Matrix A");
+    let id_mat: Matrix = (a_mat * inv_mat).expect("Failed to multiply A and its inverse");
+    for row in 0..3 {
+        for col in 0..3 {
+            let expected: f32 = if row == col { 1.0 } else { 0.0 };
+            assert!((id_mat[[row, col]] - expected).abs() < 1e-5, "Inverse not as expected");
+        }
+    }
+
+    let singular_mat: Matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.0], 2, 2)
+        .expect("Failed to create singular Matrix");
+    assert_eq!(singular_mat.determinant().expect("Failed to get determinant of singular Matrix"), 0.0, "Singular determinant not zero");
+    assert!(matches!(singular_mat.solve(&Matrix::new(vec![1.0, 1.0], 2, 1).unwrap()), Err(LAError::SingularMatrixError)), "Singular solve not rejected");
+    assert!(matches!(singular_mat.inverse(), Err(LAError::SingularMatrixError)), "Singular inverse not rejected");
+}
+
+#[test]
+fn qr_decomposition() {
+    let a_vec: Vec<f32> = vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0];
+    let b_vec: Vec<f32> = vec![1.0, 3.0, 5.0, 7.0];
+
+    let a_mat: Matrix = Matrix::new(a_vec, 4, 2)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(b_vec, 4, 1)
+        .expect("Failed to create Matrix B");
+
+    let qr = a_mat.qr();
+    let q_mat: Matrix = qr.get_q();
+    let r_mat: Matrix = qr.get_r();
+    assert_eq!(q_mat.get_rows(), 4, "Q row dimension not as expected");
+    assert_eq!(q_mat.get_cols(), 2, "Q col dimension not as expected");
+    assert_eq!(r_mat.get_rows(), 2, "R row dimension not as expected");
+    assert_eq!(r_mat.get_cols(), 2, "R col dimension not as expected");
+    assert_eq!(r_mat[[1, 0]], 0.0, "R not upper triangular");
+
+    let qr_mat: Matrix = (q_mat.clone() * r_mat).expect("Failed to multiply Q and R");
+    for (qr, a) in qr_mat.get_data().iter().zip(a_mat.get_data()) {
+        assert!((qr - a).abs() < 1e-5, "QR factors do not reproduce A");
+    }
+
+    let qtq_mat: Matrix = (q_mat.transpose() * q_mat).expect("Failed to multiply Q^T and Q");
+    for row in 0..2 {
+        for col in 0..2 {
+            let expected: f32 = if row == col { 1.0 } else { 0.0 };
+            assert!((qtq_mat[[row, col]] - expected).abs() < 1e-5, "Q columns not orthonormal");
+        }
+    }
+
+    let x_vec: Vec<f32> = vec![1.0, 2.0];
+    let x_mat: Matrix = a_mat.least_squares(&b_mat)
+        .expect("Failed to solve least squares problem");
+    for (x, expected) in x_mat.get_data().iter().zip(x_vec) {
+        assert!((x - expected).abs() < 1e-5, "Least squares solution not as expected");
+    }
+
+    let deficient_mat: Matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0], 3, 2)
+        .expect("Failed to create rank deficient Matrix");
+    assert_eq!(deficient_mat.qr().rank(), 1, "Rank not as expected");
+    assert!(matches!(deficient_mat.least_squares(&Matrix::new(vec![1.0; 3], 3, 1).unwrap()), Err(LAError::RankDeficientError)), "Rank deficient least squares not rejected");
+}
+
+#[test]
+fn cholesky_decomposition() {
+    let a_vec: Vec<f32> = vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0];
+    let l_vec: Vec<f32> = vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+    let b_vec: Vec<f32> = vec![-12.0, -31.0, 82.0];
+
+    let a_mat: Matrix = Matrix::new(a_vec, 3, 3)
+        .expect("Failed to create Matrix A");
+    let b_mat: Matrix = Matrix::new(b_vec, 3, 1)
+        .expect("Failed to create Matrix B");
+
+    let chol = a_mat.cholesky()
+        .expect("Failed to factorize Matrix A");
+
+    for (l, expected) in chol.get_l().get_data().iter().zip(l_vec) {
+        assert!((l - expected).abs() < 1e-5, "Cholesky factor not as expected");
+    }
+
+    assert!((chol.log_determinant() - 36.0_f32.ln()).abs() < 1e-5, "Log determinant not as expected");
+
+    let x_vec: Vec<f32> = vec![1.0, 0.0, 1.0];
+    let x_mat: Matrix = a_mat.solve_spd(&b_mat)
+        .expect("Failed to solve A * x = b");
+    for (x, expected) in x_mat.get_data().iter().zip(x_vec) {
+        assert!((x - expected).abs() < 1e-4, "Solution not as expected");
+    }
+
+    let indefinite_mat: Matrix = Matrix::new(vec![1.0, 2.0, 2.0, 1.0], 2, 2)
+        .expect("Failed to create indefinite Matrix");
+    assert!(matches!(indefinite_mat.cholesky(), Err(LAError::NotPositiveDefiniteError)), "Indefinite matrix not rejected");
+}
+
+#[test]
+fn eigen_decomposition() {
+    let a_vec: Vec<f32> = vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0];
+
+    let a_mat: Matrix = Matrix::new(a_vec, 3, 3)
+        .expect("Failed to create Matrix A");
+
+    let eigen = a_mat.symmetric_eigen()
+        .expect("Failed to decompose Matrix A");
+
+    let root_two: f32 = 2.0_f32.sqrt();
+    let values_vec: Vec<f32> = vec![2.0 - root_two, 2.0, 2.0 + root_two];
+    for (value, expected) in eigen.get_values().iter().zip(values_vec) {
+        assert!((value - expected).abs() < 1e-5, "Symmetric eigenvalues not as expected");
+    }
+
+    let v_mat: Matrix = eigen.get_vectors();
+    let av_mat: Matrix = (a_mat.clone() * v_mat.clone()).expect("Failed to multiply A and V");
+    for col in 0..3 {
+        for row in 0..3 {
+            let expected: f32 = eigen.get_values()[col] * v_mat[[row, col]];
+            assert!((av_mat[[row, col]] - expected).abs() < 1e-5, "Eigenvector not as expected");
+        }
+    }
+
+    let general_values = a_mat.eigenvalues()
+        .expect("Failed to get eigenvalues of Matrix A");
+    for (value, expected) in general_values.into_iter().zip(eigen.get_values()) {
+        assert!((value.re - expected).abs() < 1e-5 && value.im.abs() < 1e-5, "General eigenvalues of symmetric matrix not as expected");
+    }
+
+    let rotation_mat: Matrix = Matrix::new(vec![0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 3.0], 3, 3)
+        .expect("Failed to create rotation Matrix");
+    let rotation_values = rotation_mat.eigenvalues()
+        .expect("Failed to get eigenvalues of rotation Matrix");
+    let rotation_expected: Vec<Complex> = vec![Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)];
+    for (value, expected) in rotation_values.into_iter().zip(rotation_expected) {
+        assert!((value - expected).norm() < 1e-5, "Complex eigenvalues not as expected");
+    }
+
+    let companion_mat: Matrix = Matrix::new(vec![6.0, -11.0, 6.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 3, 3)
+        .expect("Failed to create companion Matrix");
+    let companion_values = companion_mat.eigenvalues()
+        .expect("Failed to get eigenvalues of companion Matrix");
+    for (value, expected) in companion_values.into_iter().zip([1.0, 2.0, 3.0]) {
+        assert!((value.re - expected).abs() < 1e-4 && value.im.abs() < 1e-4, "Nonsymmetric eigenvalues not as expected");
+    }
+}
+
+#[test]
+fn singular_value_decomposition() {
+    let a_vec: Vec<f32> = vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0];
+
+    let a_mat: Matrix = Matrix::new(a_vec, 2, 3)
+        .expect("Failed to create Matrix A");
+
+    let svd = a_mat.svd()
+        .expect("Failed to decompose Matrix A");
+
+    let sigma_vec: Vec<f32> = vec![5.0, 3.0];
+    for (sigma, expected) in svd.get_singular_values().iter().zip(sigma_vec) {
+        assert!((sigma - expected).abs() < 1e-5, "Singular values not as expected");
+    }
+
+    let usv_mat: Matrix = ((svd.get_u() * svd.get_s()).expect("Failed to multiply U and S") * svd.get_v().transpose())
+        .expect("Failed to multiply US and V^T");
+    for (usv, a) in usv_mat.get_data().iter().zip(a_mat.get_data()) {
+        assert!((usv - a).abs() < 1e-5, "Thin SVD does not reproduce A");
+    }
+
+    let full_svd = a_mat.full_svd()
+        .expect("Failed to fully decompose Matrix A");
+    assert_eq!(full_svd.get_v().get_rows(), 3, "Full V row dimension not as expected");
+    assert_eq!(full_svd.get_v().get_cols(), 3, "Full V col dimension not as expected");
+    let full_usv_mat: Matrix = ((full_svd.get_u() * full_svd.get_s()).expect("Failed to multiply U and S") * full_svd.get_v().transpose())
+        .expect("Failed to multiply US and V^T");
+    for (usv, a) in full_usv_mat.get_data().iter().zip(a_mat.get_data()) {
+        assert!((usv - a).abs() < 1e-5, "Full SVD does not reproduce A");
+    }
+
+    assert!((a_mat.cond().expect("Failed to get condition number") - 5.0 / 3.0).abs() < 1e-5, "Condition number not as expected");
+
+    let null_mat: Matrix = a_mat.null_space()
+        .expect("Failed to get null space of Matrix A");
+    assert_eq!(null_mat.get_cols(), 1, "Null space dimension not as expected");
+    let zero_mat: Matrix = (a_mat.clone() * null_mat).expect("Failed to multiply A and null space");
+    assert!(zero_mat.get_data().iter().all(|value| value.abs() < 1e-5), "Null space not annihilated by A");
+
+    let deficient_mat: Matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0], 3, 2)
+        .expect("Failed to create rank deficient Matrix");
+    assert_eq!(deficient_mat.rank(None).expect("Failed to get rank"), 1, "Rank not as expected");
+
+    // Pseudo-inverse of a rank one matrix x * y^T is y * x^T / (|x|^2 |y|^2)
+    let pinv_mat: Matrix = deficient_mat.pinv()
+        .expect("Failed to get pseudo-inverse");
+    let pinv_vec: Vec<f32> = vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0];
+    for (pinv, expected) in pinv_mat.get_data().iter().zip(pinv_vec) {
+        assert!((pinv - expected / 70.0).abs() < 1e-5, "Pseudo-inverse not as expected");
+    }
+}
+
+#[test]
+fn generic_matrices() {
+    let count_mat: Matrix<u32> = Matrix::new(vec![1, 2, 3, 4, 5, 6], 2, 3)
+        .expect("Failed to create integer Matrix");
+
+    let count_transpose: Matrix<u32> = count_mat.transpose();
+    assert_eq!(count_transpose.get_data(), vec![1, 4, 2, 5, 3, 6], "Integer transpose not as expected");
+
+    let gram_mat: Matrix<u32> = (count_mat.clone() * count_transpose).expect("Failed to multiply integer matrices");
+    assert_eq!(gram_mat.get_data(), vec![14, 32, 32, 77], "Integer multiplication not as expected");
+
+    let shifted_mat: Matrix<u32> = (10 - count_mat.clone()) * 2 + 1;
+    assert_eq!(shifted_mat.get_data(), vec![19, 17, 15, 13, 11, 9], "Integer scalar operations not as expected");
+
+    let diff_mat: Matrix<u32> = (shifted_mat - count_mat).expect("Failed to subtract integer matrices");
+    assert_eq!(diff_mat.get_data(), vec![18, 15, 12, 9, 6, 3], "Integer subtraction not as expected");
+
+    // 5 x 5 Hilbert matrix is too ill-conditioned to invert accurately in f32
+    let n: usize = 5;
+    let hilbert_vec: Vec<f64> = (0..n * n)
+        .map(|idx| 1.0 / ((idx / n + idx % n + 1) as f64))
+        .collect();
+    let hilbert_mat: Matrix<f64> = Matrix::new(hilbert_vec, n, n)
+        .expect("Failed to create Hilbert Matrix");
+
+    let x_vec: Vec<f64> = vec![1.0; n];
+    let b_mat: Matrix<f64> = (hilbert_mat.clone() * Matrix::new(x_vec.clone(), n, 1).unwrap())
+        .expect("Failed to multiply Hilbert Matrix");
+    let solved_mat: Matrix<f64> = hilbert_mat.solve(&b_mat)
+        .expect("Failed to solve Hilbert system");
+    for (x, expected) in solved_mat.get_data().iter().zip(x_vec) {
+        assert!((x - expected).abs() < 1e-9, "f64 solution not as expected");
+    }
+
+    let neg_mat: Matrix<f64> = -(2.0 * hilbert_mat);
+    assert_eq!(neg_mat[[0, 0]], -2.0, "f64 negation not as expected");
+}
+
+#[test]
+fn complex_matrices() {
+    let i: Complex = Complex::i();
+
+    let a_vec: Vec<Complex> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, -1.0), Complex::new(3.0, 2.0)];
+    let a_mat: Matrix<Complex> = Matrix::new(a_vec, 2, 2)
+        .expect("Failed to create complex Matrix A");
+
+    let adjoint_vec: Vec<Complex> = vec![Complex::new(1.0, -1.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0), Complex::new(3.0, -2.0)];
+    assert_eq!(a_mat.adjoint().get_data(), adjoint_vec, "Adjoint not as expected");
+    assert!(!a_mat.is_hermitian(), "Non-Hermitian matrix reported as Hermitian");
+
+    let product_mat: Matrix<Complex> = (a_mat.adjoint() * a_mat.clone())
+        .expect("Failed to multiply complex matrices");
+    let product_vec: Vec<Complex> = vec![Complex::new(3.0, 0.0), Complex::new(0.0, 1.0), Complex::new(0.0, -1.0), Complex::new(17.0, 0.0)];
+    assert_eq!(product_mat.get_data(), product_vec, "Complex multiplication not as expected");
+    assert!(product_mat.is_hermitian(), "A^H * A not reported as Hermitian");
+
+    let scaled_mat: Matrix<Complex> = i * a_mat;
+    assert_eq!(scaled_mat[[0, 0]], Complex::new(-1.0, 1.0), "Complex scaling not as expected");
+    assert_eq!(i * i, Complex::new(-1.0, 0.0), "Imaginary unit not as expected");
+    assert_eq!(Complex::new(3.0, 4.0).norm(), 5.0, "Complex magnitude not as expected");
+    assert_eq!(Complex::new(1.0, 2.0) / Complex::new(1.0, 2.0), Complex::new(1.0, 0.0), "Complex division not as expected");
+
+    let real_mat: Matrix = Matrix::new(vec![1.0, 2.0, 2.0, 1.0], 2, 2)
+        .expect("Failed to create real Matrix");
+    assert!(real_mat.is_hermitian(), "Symmetric real matrix not reported as Hermitian");
+}
+
+#[test]
+fn sparse_matrices() {
+    let triplets: Vec<(usize, usize, f32)> = vec![
+        (0, 0, 4.0),
+        (2, 3, 5.0),
+        (1, 1, 2.0),
+        (0, 3, 1.0),
+        (2, 3, 1.0)
+    ];
+
+    let dense_vec: Vec<f32> = vec![4.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 6.0];
+
+    let csr_mat: CsrMatrix = CsrMatrix::from_triplets(&triplets, 3, 4)
+        .expect("Failed to create CSR Matrix");
+    assert_eq!(csr_mat.nnz(), 4, "Duplicate triplets not summed");
+    assert_eq!(csr_mat.get_row_ptr(), vec![0, 2, 3, 4], "CSR row pointers not as expected");
+    assert_eq!(csr_mat.get(2, 3).expect("Failed to index CSR Matrix"), 6.0, "CSR indexed value not as expected");
+    assert_eq!(csr_mat.to_dense().get_data(), dense_vec, "CSR to dense not as expected");
+
+    let csc_mat: CscMatrix = CscMatrix::from_triplets(&triplets, 3, 4)
+        .expect("Failed to create CSC Matrix");
+    assert_eq!(csc_mat.get_col_ptr(), vec![0, 1, 2, 2, 4], "CSC column pointers not as expected");
+    assert_eq!(csc_mat.to_dense().get_data(), dense_vec, "CSC to dense not as expected");
+    assert_eq!(csr_mat.to_csc().to_dense().get_data(), dense_vec, "CSR to CSC not as expected");
+    assert_eq!(csc_mat.to_csr().get_values(), csr_mat.get_values(), "CSC to CSR not as expected");
+
+    let dense_mat: Matrix = Matrix::new(dense_vec, 3, 4)
+        .expect("Failed to create dense Matrix");
+    assert_eq!(CsrMatrix::from_dense(&dense_mat).get_col_idx(), csr_mat.get_col_idx(), "Dense to CSR not as expected");
+    assert_eq!(CscMatrix::from_dense(&dense_mat).get_row_idx(), csc_mat.get_row_idx(), "Dense to CSC not as expected");
+    assert_eq!(csr_mat.transpose().to_dense().get_data(), dense_mat.transpose().get_data(), "CSR transpose not as expected");
+    assert_eq!(csc_mat.transpose().to_dense().get_data(), dense_mat.transpose().get_data(), "CSC transpose not as expected");
+
+    let x_vec: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0];
+    let y_vec: Vec<f32> = vec![8.0, 4.0, 24.0];
+    assert_eq!(csr_mat.mul_vec(&x_vec).expect("Failed to multiply CSR Matrix and vector"), y_vec, "CSR-vector product not as expected");
+    assert_eq!(csc_mat.mul_vec(&x_vec).expect("Failed to multiply CSC Matrix and vector"), y_vec, "CSC-vector product not as expected");
+
+    let b_mat: Matrix = Matrix::new(vec![1.0, 0.0, 2.0, 1.0, 3.0, 0.0, 4.0, 2.0], 4, 2)
+        .expect("Failed to create Matrix B");
+    let product_vec: Vec<f32> = (dense_mat * b_mat.clone()).expect("Failed to multiply dense matrices").get_data();
+    assert_eq!(csr_mat.mul_dense(&b_mat).expect("Failed to multiply CSR Matrix and Matrix B").get_data(), product_vec, "CSR-dense product not as expected");
+    assert_eq!(csc_mat.mul_dense(&b_mat).expect("Failed to multiply CSC Matrix and Matrix B").get_data(), product_vec, "CSC-dense product not as expected");
+
+    assert!(matches!(CsrMatrix::from_triplets(&[(3, 0, 1.0)], 3, 4), Err(LAError::IndexError)), "Out of bounds triplet not rejected");
+    assert!(matches!(csr_mat.mul_vec(&[1.0, 2.0]), Err(LAError::SizeError)), "Mismatched vector not rejected");
+}
+
+#[test]
+fn iterative_solvers() {
+    // 1D Poisson matrix with diagonal 2 and off-diagonals -1
+    let n: usize = 20;
+    let mut triplets: Vec<(usize, usize, f64)> = Vec::new();
+    for idx in 0..n {
+        triplets.push((idx, idx, 2.0));
+        if idx > 0 {
+            triplets.push((idx, idx - 1, -1.0));
+        }
+        if idx + 1 < n {
+            triplets.push((idx, idx + 1, -1.0));
+        }
+    }
+    let poisson: CsrMatrix<f64> = CsrMatrix::from_triplets(&triplets, n, n)
+        .expect("Failed to create Poisson matrix");
+
+    let x_true: Vec<f64> = (0..n).map(|idx| (idx as f64 + 1.0).sin()).collect();
+    let rhs: Vec<f64> = poisson.mul_vec(&x_true).expect("Failed to build right hand side");
+    let options: SolverOptions<f64> = SolverOptions {
+        tolerance: 1e-10,
+        ..Default::default()
+    };
+
+    let max_error = |x: &[f64]| -> f64 {
+        x.iter().zip(&x_true).fold(0.0, |acc, (a, b)| acc.max((a - b).abs()))
+    };
+
+    let jacobi: Jacobi<f64> = Jacobi::new(&poisson).expect("Failed to create Jacobi preconditioner");
+    let (x_cg, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::cg(&poisson, &rhs, Some(&jacobi), &options)
+        .expect("Failed to run CG");
+    assert!(report.converged, "CG did not converge");
+    assert!(report.iterations <= n, "CG iteration count not as expected");
+    assert_eq!(report.residual_history.len(), report.iterations + 1, "CG residual history length not as expected");
+    assert!(*report.residual_history.last().unwrap() <= 1e-10, "CG final residual not as expected");
+    assert!(max_error(&x_cg) < 1e-8, "CG solution not as expected");
+
+    let ilu: Ilu0<f64> = Ilu0::new(&poisson).expect("Failed to create ILU(0) preconditioner");
+    let (x_bicg, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::bicgstab(&poisson, &rhs, Some(&ilu), &options)
+        .expect("Failed to run BiCGSTAB");
+    assert!(report.converged, "BiCGSTAB did not converge");
+    assert!(report.iterations <= 2, "ILU(0) of tridiagonal matrix not exact");
+    assert!(max_error(&x_bicg) < 1e-8, "BiCGSTAB solution not as expected");
+
+    // Nonsymmetric convection-diffusion style matrix
+    let nonsym: Matrix<f64> = Matrix::new(vec![
+        4.0, -2.0, 0.0, 0.0,
+        -1.0, 4.0, -2.0, 0.0,
+        0.0, -1.0, 4.0, -2.0,
+        0.0, 0.0, -1.0, 4.0
+    ], 4, 4).expect("Failed to create nonsymmetric Matrix");
+    let b_vec: Vec<f64> = vec![2.0, 1.0, 1.0, 3.0];
+    let direct: Vec<f64> = nonsym.solve(&Matrix::new(b_vec.clone(), 4, 1).unwrap())
+        .expect("Failed to solve directly")
+        .get_data();
+
+    let restart_options: SolverOptions<f64> = SolverOptions {
+        tolerance: 1e-10,
+        restart: 2,
+        ..Default::default()
+    };
+    let (x_gmres, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::gmres(&nonsym, &b_vec, None, &restart_options)
+        .expect("Failed to run GMRES");
+    assert!(report.converged, "GMRES did not converge");
+    assert!(report.residual_history.windows(2).all(|pair| pair[1] <= pair[0] + 1e-12), "GMRES residuals not monotone");
+    for (x, y) in x_gmres.iter().zip(&direct) {
+        assert!((x - y).abs() < 1e-8, "GMRES solution not as expected");
+    }
+
+    // Eigenvector right hand side breaks down after one exact step
+    let upper: Matrix<f64> = Matrix::new(vec![2.0, 1.0, 0.0, 3.0], 2, 2).expect("Failed to create upper triangular Matrix");
+    let (x_eigen, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::gmres(&upper, &[4.0, 0.0], None, &restart_options)
+        .expect("Failed to run GMRES on eigenvector");
+    assert!(report.converged && report.iterations == 1, "GMRES lucky breakdown not handled");
+    assert!((x_eigen[0] - 2.0).abs() < 1e-12 && x_eigen[1].abs() < 1e-12, "GMRES eigenvector solution not as expected");
+
+    // Right hand side in the null space of a singular matrix gives a zero Hessenberg diagonal
+    let singular: Matrix<f64> = Matrix::new(vec![1.0, 0.0, 0.0, 0.0], 2, 2).expect("Failed to create singular Matrix");
+    let (x_singular, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::gmres(&singular, &[0.0, 1.0], None, &restart_options)
+        .expect("Failed to run GMRES on singular Matrix");
+    assert!(!report.converged, "GMRES breakdown on singular Matrix reported as converged");
+    assert!(x_singular.iter().all(|x| x.is_finite()), "GMRES breakdown produced non-finite solution");
+
+    let limited: SolverOptions<f64> = SolverOptions {
+        tolerance: 1e-14,
+        max_iterations: 3,
+        ..Default::default()
+    };
+    let (_, report): (Vec<f64>, ConvergenceReport<f64>) = krylov::cg(&poisson, &rhs, None, &limited)
+        .expect("Failed to run limited CG");
+    assert!(!report.converged && report.iterations == 3, "Iteration limit not respected");
+
+    assert!(matches!(krylov::cg(&poisson, &[1.0, 2.0], None, &options), Err(LAError::SizeError)), "Mismatched right hand side not rejected");
+}